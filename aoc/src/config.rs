@@ -0,0 +1,171 @@
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::OutputFormat;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub inputs_dir: Option<PathBuf>,
+    #[serde(default)]
+    pub output_format: OutputFormat,
+    // only kept in the profile, nothing downloads inputs with it
+    pub session_token_path: Option<PathBuf>,
+    pub days: Option<Vec<String>>,
+    #[serde(default)]
+    pub bench: BenchConfig,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BenchConfig {
+    pub sample_size: usize,
+    pub warm_up_secs: u64,
+    pub measurement_secs: u64,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            sample_size: 10,
+            warm_up_secs: 3,
+            measurement_secs: 5,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(PathBuf, io::Error),
+    Parse(PathBuf, toml::de::Error),
+    UnknownDay(String),
+    InvalidBench(String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Io(path, e) => write!(f, "could not read {}: {e}", path.display()),
+            Self::Parse(path, e) => write!(f, "invalid config {}: {e}", path.display()),
+            Self::UnknownDay(day) => write!(f, "unknown day: {day}"),
+            Self::InvalidBench(reason) => write!(f, "invalid bench settings: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl Config {
+    pub fn parse(input: &str, path: &Path) -> Result<Self, ConfigError> {
+        let mut config: Config =
+            toml::from_str(input).map_err(|e| ConfigError::Parse(path.to_path_buf(), e))?;
+
+        // relative paths in the file are relative to the file, not to the working directory
+        let base = path.parent().unwrap_or(Path::new("."));
+        config.inputs_dir = config.inputs_dir.map(|dir| base.join(dir));
+        config.session_token_path = config.session_token_path.map(|p| base.join(p));
+
+        Ok(config)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        let input = fs::read_to_string(path).map_err(|e| ConfigError::Io(path.to_path_buf(), e))?;
+        Self::parse(&input, path)
+    }
+
+    pub fn load_or_default(path: &Path) -> Result<Self, ConfigError> {
+        if path.exists() {
+            Self::load(path)
+        } else {
+            Ok(Self::default())
+        }
+    }
+
+    pub fn validate(&self, known_days: &[&str]) -> Result<(), ConfigError> {
+        for day in self.days.iter().flatten() {
+            if !known_days.contains(&day.as_str()) {
+                return Err(ConfigError::UnknownDay(day.clone()));
+            }
+        }
+        if self.bench.sample_size < 10 {
+            return Err(ConfigError::InvalidBench(
+                "sample_size must be at least 10".to_string(),
+            ));
+        }
+        if self.bench.measurement_secs == 0 {
+            return Err(ConfigError::InvalidBench(
+                "measurement_secs must be positive".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn is_enabled(&self, day: &str) -> bool {
+        self.days
+            .as_ref()
+            .is_none_or(|days| days.iter().any(|d| d == day))
+    }
+
+    pub fn input_path(&self, workspace: &Path, day: &str) -> PathBuf {
+        match &self.inputs_dir {
+            Some(dir) => dir.join(format!("{day}.txt")),
+            None => workspace.join(day).join("src/input.txt"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAYS: &[&str] = &["day01", "day02"];
+
+    #[test]
+    fn parse_full_config() {
+        let config = Config::parse(
+            r#"
+inputs_dir = "inputs"
+output_format = "json"
+days = ["day02"]
+
+[bench]
+sample_size = 20
+warm_up_secs = 1
+measurement_secs = 2
+"#,
+            Path::new("/workspace/aoc.toml"),
+        )
+        .unwrap();
+
+        assert_eq!(config.output_format, OutputFormat::Json);
+        assert_eq!(
+            config.input_path(Path::new("/workspace"), "day02"),
+            Path::new("/workspace/inputs/day02.txt")
+        );
+        assert!(!config.is_enabled("day01"));
+        assert_eq!(config.bench.sample_size, 20);
+        assert!(config.validate(DAYS).is_ok());
+    }
+
+    #[test]
+    fn unknown_keys_are_rejected() {
+        let error = Config::parse("output_fromat = \"json\"", Path::new("aoc.toml")).unwrap_err();
+        assert!(error.to_string().contains("unknown field `output_fromat`"));
+
+        let error = Config::parse("[bench]\nsamples = 3", Path::new("aoc.toml")).unwrap_err();
+        assert!(error.to_string().contains("unknown field `samples`"));
+    }
+
+    #[test]
+    fn unknown_days_are_rejected() {
+        let config = Config::parse("days = [\"day42\"]", Path::new("aoc.toml")).unwrap();
+        assert!(matches!(
+            config.validate(DAYS),
+            Err(ConfigError::UnknownDay(day)) if day == "day42"
+        ));
+    }
+}
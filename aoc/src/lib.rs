@@ -1,4 +1,12 @@
-use std::time::{Duration, Instant};
+pub mod config;
+pub mod stream;
+
+use serde::Deserialize;
+use std::{
+    fmt::Display,
+    str::FromStr,
+    time::{Duration, Instant},
+};
 
 pub struct Timer {
     pub start: Instant,
@@ -21,3 +29,121 @@ impl Default for Timer {
         Self::new()
     }
 }
+
+pub trait Solution {
+    type Parsed<'a>;
+    type Output1: Display;
+    type Output2: Display;
+
+    fn parse(input: &str) -> Self::Parsed<'_>;
+    fn part1(parsed: &Self::Parsed<'_>) -> Self::Output1;
+    fn part2(parsed: &Self::Parsed<'_>) -> Self::Output2;
+
+    fn solve1(input: &str) -> Self::Output1 {
+        Self::part1(&Self::parse(input))
+    }
+
+    fn solve2(input: &str) -> Self::Output2 {
+        Self::part2(&Self::parse(input))
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err(format!(
+                "invalid output format: {input} (expected text or json)"
+            )),
+        }
+    }
+}
+
+pub struct Report {
+    pub parse: Duration,
+    pub result1: String,
+    pub part1: Duration,
+    pub result2: String,
+    pub part2: Duration,
+}
+
+impl Report {
+    pub fn measure<S: Solution>(input: &str) -> Self {
+        Self::measure_with(|| S::parse(input), |p| S::part1(p), |p| S::part2(p))
+    }
+
+    // for solutions that take settings on top of the input
+    pub fn measure_with<P, R1: Display, R2: Display>(
+        parse: impl FnOnce() -> P,
+        part1: impl FnOnce(&P) -> R1,
+        part2: impl FnOnce(&P) -> R2,
+    ) -> Self {
+        let timer = Timer::new();
+        let parsed = parse();
+        let parse = timer.elapsed();
+
+        let timer = Timer::new();
+        let result1 = part1(&parsed).to_string();
+        let part1 = timer.elapsed();
+
+        let timer = Timer::new();
+        let result2 = part2(&parsed).to_string();
+        let part2 = timer.elapsed();
+
+        Self {
+            parse,
+            result1,
+            part1,
+            result2,
+            part2,
+        }
+    }
+
+    pub fn print_text(&self) {
+        println!("Parse: ({}ms)", self.parse.as_millis());
+        println!("Part 1: {} ({}ms)", self.result1, self.part1.as_millis());
+        println!("Part 2: {} ({}ms)", self.result2, self.part2.as_millis());
+    }
+
+    pub fn to_json(&self, name: &str) -> String {
+        format!(
+            r#"{{"day":{},"parse_ms":{},"part1":{},"part1_ms":{},"part2":{},"part2_ms":{}}}"#,
+            json_string(name),
+            self.parse.as_millis(),
+            json_string(&self.result1),
+            self.part1.as_millis(),
+            json_string(&self.result2),
+            self.part2.as_millis()
+        )
+    }
+}
+
+fn json_string(input: &str) -> String {
+    format!("\"{}\"", input.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+pub fn run<S: Solution>(input: &str) {
+    Report::measure::<S>(input).print_text();
+}
+
+pub fn run_named<S: Solution>(name: &str, input: &str, format: OutputFormat) {
+    let report = Report::measure::<S>(input);
+    match format {
+        OutputFormat::Text => {
+            println!("{name}");
+            report.print_text();
+        }
+        OutputFormat::Json => println!("{}", report.to_json(name)),
+    }
+}
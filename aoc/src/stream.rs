@@ -0,0 +1,104 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader},
+    path::Path,
+};
+
+use crate::{Solution, Timer};
+
+pub struct Records<R> {
+    reader: R,
+    delimiter: u8,
+    skip_blank: bool,
+}
+
+impl<R: BufRead> Iterator for Records<R> {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let mut buf = Vec::new();
+            match self.reader.read_until(self.delimiter, &mut buf) {
+                Ok(0) => return None,
+                Ok(_) => {
+                    if buf.last() == Some(&self.delimiter) {
+                        buf.pop();
+                    }
+                    // tolerate a trailing newline after the last comma-separated record and CRLF lines
+                    while matches!(buf.last(), Some(b'\n' | b'\r')) {
+                        buf.pop();
+                    }
+                    if self.skip_blank && buf.iter().all(u8::is_ascii_whitespace) {
+                        continue;
+                    }
+                    return Some(
+                        String::from_utf8(buf)
+                            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+                    );
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+pub fn records<R: BufRead>(reader: R, delimiter: u8) -> Records<R> {
+    Records {
+        reader,
+        delimiter,
+        skip_blank: false,
+    }
+}
+
+// blank lines, such as the ones at the end of a file, are skipped
+pub fn lines<R: BufRead>(reader: R) -> Records<R> {
+    Records {
+        reader,
+        delimiter: b'\n',
+        skip_blank: true,
+    }
+}
+
+pub trait StreamingSolution: Solution {
+    fn part1_streaming<R: BufRead>(reader: R) -> io::Result<Self::Output1>;
+    fn part2_streaming<R: BufRead>(reader: R) -> io::Result<Self::Output2>;
+}
+
+pub fn run_streaming<S: StreamingSolution>(path: &Path) -> io::Result<()> {
+    let timer = Timer::new();
+    let result1 = S::part1_streaming(BufReader::new(File::open(path)?))?;
+    println!("Part 1: {} ({}ms)", result1, timer.elapsed().as_millis());
+
+    let timer = Timer::new();
+    let result2 = S::part2_streaming(BufReader::new(File::open(path)?))?;
+    println!("Part 2: {} ({}ms)", result2, timer.elapsed().as_millis());
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_without_trailing_newline() {
+        let result: Vec<String> = lines("a\nbc\r\nd".as_bytes()).map(Result::unwrap).collect();
+        assert_eq!(result, vec!["a", "bc", "d"]);
+    }
+
+    #[test]
+    fn lines_skip_blank_lines() {
+        let result: Vec<String> = lines("a\n\n  \nb\n\n\n".as_bytes())
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(result, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn records_with_trailing_newline() {
+        let result: Vec<String> = records("rn=1,cm-\n".as_bytes(), b',')
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(result, vec!["rn=1", "cm-"]);
+    }
+}
@@ -0,0 +1,87 @@
+use aoc::{config::Config, Solution};
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::{hint::black_box, path::Path, time::Duration};
+
+fn load_config() -> Config {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("aoc.toml");
+    let config = Config::load_or_default(&path).unwrap_or_else(|e| panic!("{e}"));
+    config.validate(DAYS).unwrap_or_else(|e| panic!("{e}"));
+    config
+}
+
+fn criterion_config() -> Criterion {
+    let bench = load_config().bench;
+    Criterion::default()
+        .sample_size(bench.sample_size)
+        .warm_up_time(Duration::from_secs(bench.warm_up_secs))
+        .measurement_time(Duration::from_secs(bench.measurement_secs))
+}
+
+fn bench_day<S: Solution>(c: &mut Criterion, name: &str, real: &str, example: &str) {
+    let mut group = c.benchmark_group(name);
+
+    for (input_name, input) in [("example", example), ("real", real)] {
+        group.bench_function(format!("parse/{input_name}"), |b| {
+            b.iter(|| S::parse(black_box(input)))
+        });
+
+        let parsed = S::parse(input);
+        group.bench_function(format!("part1/{input_name}"), |b| {
+            b.iter(|| S::part1(black_box(&parsed)))
+        });
+        group.bench_function(format!("part2/{input_name}"), |b| {
+            b.iter(|| S::part2(black_box(&parsed)))
+        });
+    }
+
+    group.finish();
+}
+
+macro_rules! bench_days {
+    ($($day:ident::$solution:ident),* $(,)?) => {
+        const DAYS: &[&str] = &[$(stringify!($day)),*];
+
+        fn days(c: &mut Criterion) {
+            let config = load_config();
+            $(
+                if config.is_enabled(stringify!($day)) {
+                    bench_day::<$day::$solution>(
+                        c,
+                        stringify!($day),
+                        include_str!(concat!("../", stringify!($day), "/src/input.txt")),
+                        $day::EXAMPLE,
+                    );
+                }
+            )*
+        }
+    };
+}
+
+bench_days!(
+    day01::Day01,
+    day02::Day02,
+    day03::Day03,
+    day04::Day04,
+    day05::Day05,
+    day06::Day06,
+    day07::Day07,
+    day08::Day08,
+    day09::Day09,
+    day10::Day10,
+    day11::Day11,
+    day12::Day12,
+    day13::Day13,
+    day14::Day14,
+    day15::Day15,
+    day16::Day16,
+    day17::Day17,
+    day18::Day18,
+    day19::Day19,
+);
+
+criterion_group! {
+    name = benches;
+    config = criterion_config();
+    targets = days
+}
+criterion_main!(benches);
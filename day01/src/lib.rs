@@ -0,0 +1,289 @@
+use aho_corasick::AhoCorasick;
+use aoc::stream::{self, StreamingSolution};
+use aoc::Solution;
+use std::{
+    cmp::Reverse,
+    fmt, fs,
+    io::{self, BufRead},
+    path::Path,
+};
+
+pub const DIGIT_NAMES: &[(&str, u32)] = &[
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+pub struct DigitVocabulary {
+    pub tokens: Vec<(String, u32)>,
+    automaton: AhoCorasick,
+}
+
+impl DigitVocabulary {
+    pub fn new(tokens: Vec<(String, u32)>) -> Self {
+        let automaton = AhoCorasick::new(tokens.iter().map(|(token, _)| token)).unwrap();
+        Self { tokens, automaton }
+    }
+
+    pub fn digits() -> Self {
+        Self::new((0..10).map(|n| (n.to_string(), n)).collect())
+    }
+
+    pub fn english() -> Self {
+        let mut tokens: Vec<(String, u32)> = DIGIT_NAMES
+            .iter()
+            .map(|(_, n)| (n.to_string(), *n))
+            .collect();
+        tokens.extend(DIGIT_NAMES.iter().map(|(s, n)| (s.to_string(), *n)));
+        Self::new(tokens)
+    }
+
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name {
+            "digits" => Some(Self::digits()),
+            "english" => Some(Self::english()),
+            _ => None,
+        }
+    }
+
+    // one `token value` pair per line, blank lines and `#` comments are ignored
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let mut tokens = Vec::new();
+        for (line_num, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (token, value_str) = line
+                .split_once(char::is_whitespace)
+                .ok_or_else(|| format!("line {}: expected `token value`", line_num + 1))?;
+            let value = value_str
+                .trim()
+                .parse()
+                .map_err(|_| format!("line {}: invalid value: {}", line_num + 1, value_str))?;
+            tokens.push((token.to_string(), value));
+        }
+        if tokens.is_empty() {
+            return Err("vocabulary has no tokens".to_string());
+        }
+        Ok(Self::new(tokens))
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let input = fs::read_to_string(path)
+            .map_err(|e| format!("could not read {}: {e}", path.display()))?;
+        Self::parse(&input)
+    }
+
+    // overlapping matches so that e.g. `eightwo` ends in `two`; at the same position the
+    // longer token wins
+    pub fn find_first_and_last(&self, line: &str) -> Option<(TokenMatch<'_>, TokenMatch<'_>)> {
+        let matches: Vec<TokenMatch> = self
+            .automaton
+            .find_overlapping_iter(line)
+            .map(|m| {
+                let (token, value) = &self.tokens[m.pattern().as_usize()];
+                TokenMatch {
+                    token,
+                    offset: m.start(),
+                    value: *value,
+                }
+            })
+            .collect();
+
+        let first = matches
+            .iter()
+            .min_by_key(|m| (m.offset, Reverse(m.token.len())))?;
+        let last = matches.iter().max_by_key(|m| (m.offset, m.token.len()))?;
+        Some((*first, *last))
+    }
+
+    pub fn first_and_last(&self, line: &str) -> Option<(u32, u32)> {
+        self.find_first_and_last(line)
+            .map(|(first, last)| (first.value, last.value))
+    }
+
+    pub fn calibration_value(&self, line: &str) -> u32 {
+        let (first, last) = self
+            .first_and_last(line)
+            .unwrap_or_else(|| panic!("no digit in line: {line}"));
+        first * 10 + last
+    }
+
+    pub fn calibration_sum<'a>(&self, lines: impl IntoIterator<Item = &'a str>) -> u32 {
+        lines
+            .into_iter()
+            .map(|line| self.calibration_value(line))
+            .sum()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenMatch<'a> {
+    pub token: &'a str,
+    pub offset: usize,
+    pub value: u32,
+}
+
+pub struct LineReport<'a> {
+    pub line_num: usize,
+    pub line: &'a str,
+    pub matches: Option<(TokenMatch<'a>, TokenMatch<'a>)>,
+}
+
+impl LineReport<'_> {
+    pub fn value(&self) -> Option<u32> {
+        self.matches
+            .map(|(first, last)| first.value * 10 + last.value)
+    }
+}
+
+impl fmt::Display for LineReport<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.matches {
+            Some((first, last)) => write!(
+                f,
+                "{:>5}: {:?}@{} .. {:?}@{} -> {:>2}  {}",
+                self.line_num,
+                first.token,
+                first.offset,
+                last.token,
+                last.offset,
+                first.value * 10 + last.value,
+                self.line
+            ),
+            None => write!(f, "{:>5}: NO DIGIT  {}", self.line_num, self.line),
+        }
+    }
+}
+
+pub fn report<'a>(vocabulary: &'a DigitVocabulary, input: &'a str) -> Vec<LineReport<'a>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| LineReport {
+            line_num: i + 1,
+            line,
+            matches: vocabulary.find_first_and_last(line),
+        })
+        .collect()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Parsed<'a> = Vec<&'a str>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Vec<&str> {
+        input.lines().collect()
+    }
+
+    fn part1(lines: &Vec<&str>) -> u32 {
+        DigitVocabulary::digits().calibration_sum(lines.iter().copied())
+    }
+
+    fn part2(lines: &Vec<&str>) -> u32 {
+        DigitVocabulary::english().calibration_sum(lines.iter().copied())
+    }
+}
+
+impl StreamingSolution for Day01 {
+    fn part1_streaming<R: BufRead>(reader: R) -> io::Result<u32> {
+        let vocabulary = DigitVocabulary::digits();
+        stream::lines(reader)
+            .map(|line| line.map(|l| vocabulary.calibration_value(&l)))
+            .sum()
+    }
+
+    fn part2_streaming<R: BufRead>(reader: R) -> io::Result<u32> {
+        let vocabulary = DigitVocabulary::english();
+        stream::lines(reader)
+            .map(|line| line.map(|l| vocabulary.calibration_value(&l)))
+            .sum()
+    }
+}
+
+pub const EXAMPLE: &str = "\
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example1() {
+        assert_eq!(Day01::solve1(EXAMPLE), 142);
+    }
+
+    #[test]
+    fn example2() {
+        let input = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+        assert_eq!(Day01::solve2(input), 281);
+    }
+
+    #[test]
+    fn streaming() {
+        let input = "two1nine\neightwothree\n\n";
+        assert_eq!(Day01::part1_streaming(EXAMPLE.as_bytes()).unwrap(), 142);
+        assert_eq!(Day01::part2_streaming(input.as_bytes()).unwrap(), 29 + 83);
+    }
+
+    #[test]
+    fn overlapping_names() {
+        let vocabulary = DigitVocabulary::english();
+        assert_eq!(vocabulary.first_and_last("eightwo"), Some((8, 2)));
+        assert_eq!(vocabulary.first_and_last("xtwone3four"), Some((2, 4)));
+        assert_eq!(vocabulary.first_and_last("abc"), None);
+    }
+
+    #[test]
+    fn custom_vocabulary() {
+        let vocabulary = DigitVocabulary::parse(
+            "\
+# roman numerals
+i 1
+ii 2
+iii 3
+iv 4
+v 5",
+        )
+        .unwrap();
+        assert_eq!(vocabulary.first_and_last("xiiix"), Some((3, 1)));
+        assert_eq!(vocabulary.calibration_value("aivbv"), 45);
+
+        assert!(DigitVocabulary::parse("eins").is_err());
+        assert!(DigitVocabulary::parse("eins one").is_err());
+    }
+
+    #[test]
+    fn line_report() {
+        let vocabulary = DigitVocabulary::english();
+        let lines = report(&vocabulary, "xtwone3four\nabc");
+
+        let (first, last) = lines[0].matches.unwrap();
+        assert_eq!((first.token, first.offset), ("two", 1));
+        assert_eq!((last.token, last.offset), ("four", 7));
+        assert_eq!(lines[0].value(), Some(24));
+
+        assert_eq!(lines[1].value(), None);
+        assert_eq!(lines[1].to_string(), "    2: NO DIGIT  abc");
+    }
+}
@@ -0,0 +1,327 @@
+use aoc::stream::{self, StreamingSolution};
+use aoc::Solution;
+use std::io::{self, BufRead};
+use std::{
+    cmp::max,
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+    iter::Sum,
+};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CubeSet {
+    pub counts: BTreeMap<String, i32>,
+}
+
+impl Sum for CubeSet {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        let mut counts: BTreeMap<String, i32> = BTreeMap::new();
+        for set in iter {
+            for (color, n) in set.counts {
+                *counts.entry(color).or_default() += n;
+            }
+        }
+        CubeSet { counts }
+    }
+}
+
+impl CubeSet {
+    pub fn parse(input: &str) -> CubeSet {
+        input
+            .split(", ")
+            .map(|part| {
+                let (n_str, color_str) = part.split_once(' ').unwrap();
+                let n: i32 = n_str.parse().unwrap();
+
+                CubeSet {
+                    counts: BTreeMap::from([(color_str.to_string(), n)]),
+                }
+            })
+            .sum()
+    }
+
+    pub fn get(&self, color: &str) -> i32 {
+        self.counts.get(color).copied().unwrap_or(0)
+    }
+
+    pub fn is_possible(&self, config: &CubeSet) -> bool {
+        self.counts.iter().all(|(color, n)| *n <= config.get(color))
+    }
+
+    pub fn power(&self) -> i32 {
+        self.counts.values().product()
+    }
+
+    pub fn total(&self) -> i32 {
+        self.counts.values().sum()
+    }
+
+    fn set(&mut self, color: &str, n: i32) {
+        if n == 0 {
+            self.counts.remove(color);
+        } else {
+            self.counts.insert(color.to_string(), n);
+        }
+    }
+
+    pub fn union(&self, other: &CubeSet) -> CubeSet {
+        let mut counts = self.counts.clone();
+        for (color, n) in &other.counts {
+            let count = counts.entry(color.clone()).or_default();
+            *count = max(*count, *n);
+        }
+        CubeSet { counts }
+    }
+}
+
+impl fmt::Display for CubeSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let parts: Vec<String> = self
+            .counts
+            .iter()
+            .map(|(color, n)| format!("{n} {color}"))
+            .collect();
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+pub struct Game {
+    pub id: i32,
+    pub draws: Vec<CubeSet>,
+}
+
+impl Game {
+    pub fn parse(input: &str) -> Game {
+        let (id_part, draws_part) = input.split_once(": ").unwrap();
+        let id: i32 = id_part.strip_prefix("Game ").unwrap().parse().unwrap();
+
+        let draws = draws_part.split("; ").map(CubeSet::parse).collect();
+
+        Game { id, draws }
+    }
+
+    pub fn is_possible(&self, config: &CubeSet) -> bool {
+        self.draws.iter().all(|draw| draw.is_possible(config))
+    }
+
+    pub fn min_config(&self) -> CubeSet {
+        self.draws
+            .iter()
+            .fold(CubeSet::default(), |acc, d| acc.union(d))
+    }
+}
+
+pub const BAG: &str = "12 red, 13 green, 14 blue";
+
+pub fn parse(input: &str) -> Vec<Game> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(Game::parse)
+        .collect()
+}
+
+pub fn possible_ids(games: &[Game], config: &CubeSet) -> Vec<i32> {
+    games
+        .iter()
+        .filter(|game| game.is_possible(config))
+        .map(|game| game.id)
+        .collect()
+}
+
+pub fn solve1(games: &[Game], config: &CubeSet) -> i32 {
+    possible_ids(games, config).iter().sum()
+}
+
+// Bags that make at least `k` games possible and can't lose a single cube without
+// dropping below `k`, ordered by total number of cubes.
+pub fn pareto_frontier(games: &[Game], k: usize) -> Vec<CubeSet> {
+    if k > games.len() {
+        return Vec::new();
+    }
+    if k == 0 {
+        return vec![CubeSet::default()];
+    }
+    let configs: Vec<CubeSet> = games.iter().map(Game::min_config).collect();
+    let colors: BTreeSet<&str> = configs
+        .iter()
+        .flat_map(|c| c.counts.keys().map(String::as_str))
+        .collect();
+    let colors: Vec<&str> = colors.into_iter().collect();
+    let Some((last, rest)) = colors.split_last() else {
+        return vec![CubeSet::default()];
+    };
+
+    let mut candidates = Vec::new();
+    collect_candidates(
+        &configs,
+        k,
+        rest,
+        last,
+        &mut CubeSet::default(),
+        &mut candidates,
+    );
+
+    let mut frontier: Vec<CubeSet> = candidates
+        .iter()
+        .filter(|c| !candidates.iter().any(|o| o != *c && o.is_possible(c)))
+        .cloned()
+        .collect();
+    frontier.sort_by_key(|c| c.total());
+    frontier.dedup();
+    frontier
+}
+
+// Every count on the frontier is some game's requirement, so try all of them for each
+// colour but the last; the last one is then the k-th smallest of the remaining games.
+fn collect_candidates(
+    configs: &[CubeSet],
+    k: usize,
+    colors: &[&str],
+    last: &str,
+    bag: &mut CubeSet,
+    candidates: &mut Vec<CubeSet>,
+) {
+    match colors.split_first() {
+        Some((color, rest)) => {
+            let values: BTreeSet<i32> = configs.iter().map(|c| c.get(color)).collect();
+            for n in values {
+                bag.set(color, n);
+                collect_candidates(configs, k, rest, last, bag, candidates);
+            }
+            bag.set(color, 0);
+        }
+        None => {
+            let mut needed: Vec<i32> = configs
+                .iter()
+                .filter(|c| {
+                    c.counts
+                        .iter()
+                        .all(|(color, n)| color == last || *n <= bag.get(color))
+                })
+                .map(|c| c.get(last))
+                .collect();
+            if needed.len() >= k {
+                needed.sort_unstable();
+                let mut candidate = bag.clone();
+                candidate.set(last, needed[k - 1]);
+                candidates.push(candidate);
+            }
+        }
+    }
+}
+
+pub fn minimal_bag(games: &[Game], k: usize) -> Option<CubeSet> {
+    pareto_frontier(games, k).into_iter().next()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Parsed<'a> = Vec<Game>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Vec<Game> {
+        parse(input)
+    }
+
+    fn part1(games: &Vec<Game>) -> i32 {
+        solve1(games, &CubeSet::parse(BAG))
+    }
+
+    fn part2(games: &Vec<Game>) -> i32 {
+        games
+            .iter()
+            .map(|g| g.min_config())
+            .map(|c| c.power())
+            .sum()
+    }
+}
+
+impl StreamingSolution for Day02 {
+    fn part1_streaming<R: BufRead>(reader: R) -> io::Result<i32> {
+        let bag = CubeSet::parse(BAG);
+        stream::lines(reader)
+            .map(|line| line.map(|l| Game::parse(&l)))
+            .filter(|game| game.as_ref().map_or(true, |g| g.is_possible(&bag)))
+            .map(|game| game.map(|g| g.id))
+            .sum()
+    }
+
+    fn part2_streaming<R: BufRead>(reader: R) -> io::Result<i32> {
+        stream::lines(reader)
+            .map(|line| line.map(|l| Game::parse(&l).min_config().power()))
+            .sum()
+    }
+}
+
+pub const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example1() {
+        assert_eq!(solve1(&parse(EXAMPLE), &CubeSet::parse(BAG)), 8);
+    }
+
+    #[test]
+    fn example2() {
+        assert_eq!(Day02::solve2(EXAMPLE), 2286);
+    }
+
+    #[test]
+    fn streaming() {
+        assert_eq!(Day02::part1_streaming(EXAMPLE.as_bytes()).unwrap(), 8);
+        assert_eq!(Day02::part2_streaming(EXAMPLE.as_bytes()).unwrap(), 2286);
+    }
+
+    #[test]
+    fn trailing_blank_lines() {
+        let input = format!("{EXAMPLE}\n\n");
+        assert_eq!(Day02::solve1(&input), 8);
+        assert_eq!(Day02::part2_streaming(input.as_bytes()).unwrap(), 2286);
+    }
+
+    #[test]
+    fn arbitrary_colors() {
+        let game = Game::parse("Game 7: 2 cyan, 1 red; 3 magenta, 1 cyan");
+        let min_config = game.min_config();
+        assert_eq!(min_config.to_string(), "2 cyan, 3 magenta, 1 red");
+        assert_eq!(min_config.power(), 6);
+
+        assert!(game.is_possible(&CubeSet::parse("2 cyan, 3 magenta, 1 red, 9 blue")));
+        assert!(!game.is_possible(&CubeSet::parse(BAG)));
+    }
+
+    #[test]
+    fn bag_queries() {
+        let games = parse(EXAMPLE);
+        assert_eq!(possible_ids(&games, &CubeSet::parse(BAG)), vec![1, 2, 5]);
+
+        let frontier: Vec<String> = pareto_frontier(&games, 1)
+            .iter()
+            .map(|bag| bag.to_string())
+            .collect();
+        assert_eq!(
+            frontier,
+            vec![
+                "4 blue, 3 green, 1 red",
+                "2 blue, 3 green, 6 red",
+                "6 blue, 2 green, 4 red"
+            ]
+        );
+
+        let all = minimal_bag(&games, 5).unwrap();
+        assert_eq!(all, CubeSet::parse("20 red, 13 green, 15 blue"));
+        assert_eq!(possible_ids(&games, &all).len(), 5);
+        assert_eq!(minimal_bag(&games, 6), None);
+    }
+}
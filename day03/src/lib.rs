@@ -0,0 +1,388 @@
+use aoc::Solution;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+#[derive(Hash, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct Coord {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Coord {
+    pub fn surrounding(&self) -> impl Iterator<Item = Coord> + '_ {
+        let start_row = self.row.saturating_sub(1);
+        let start_col = self.col.saturating_sub(1);
+
+        (start_row..self.row + 2)
+            .flat_map(move |row| (start_col..self.col + 2).map(move |col| Coord { row, col }))
+            .filter(move |here| here != self)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PartNumber {
+    pub number: i32,
+    pub coords: HashSet<Coord>,
+}
+
+#[derive(Debug)]
+pub struct Schematic {
+    pub part_numbers: Vec<PartNumber>,
+    pub symbols: HashMap<Coord, char>,
+    // every digit cell -> index into `part_numbers`
+    pub cells: HashMap<Coord, usize>,
+}
+
+impl Schematic {
+    pub fn numbers_touching(&self, coord: Coord) -> Vec<usize> {
+        let mut ids: Vec<usize> = coord
+            .surrounding()
+            .filter_map(|here| self.cells.get(&here).copied())
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    }
+
+    pub fn numbers_touching_symbols(&self) -> HashSet<usize> {
+        self.symbols
+            .keys()
+            .flat_map(|coord| self.numbers_touching(*coord))
+            .collect()
+    }
+
+    pub fn matching(&self, rule: &SymbolRule) -> Vec<SymbolMatch> {
+        let mut matches: Vec<SymbolMatch> = self
+            .symbols
+            .iter()
+            .filter(|(_, symbol)| **symbol == rule.symbol)
+            .filter_map(|(coord, symbol)| {
+                let numbers: Vec<i32> = self
+                    .numbers_touching(*coord)
+                    .into_iter()
+                    .map(|id| self.part_numbers[id].number)
+                    .collect();
+                rule.matches(&numbers).then(|| SymbolMatch {
+                    coord: *coord,
+                    symbol: *symbol,
+                    value: rule.aggregate.apply(&numbers),
+                    numbers,
+                })
+            })
+            .collect();
+        matches.sort_by_key(|m| m.coord);
+        matches
+    }
+
+    pub fn evaluate(&self, rule: &SymbolRule) -> i64 {
+        self.matching(rule).iter().map(|m| m.value).sum()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Count {
+    Exactly(usize),
+    AtLeast(usize),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Aggregate {
+    Product,
+    Sum,
+    Max,
+}
+
+impl Aggregate {
+    pub fn apply(&self, numbers: &[i32]) -> i64 {
+        let numbers = numbers.iter().map(|n| i64::from(*n));
+        match self {
+            Aggregate::Product => numbers.product(),
+            Aggregate::Sum => numbers.sum(),
+            Aggregate::Max => numbers.max().unwrap_or(0),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SymbolRule {
+    pub symbol: char,
+    pub count: Count,
+    pub aggregate: Aggregate,
+}
+
+impl SymbolRule {
+    pub const GEAR: SymbolRule = SymbolRule {
+        symbol: '*',
+        count: Count::Exactly(2),
+        aggregate: Aggregate::Product,
+    };
+
+    // `<symbol> <count> <aggregate>`, e.g. `* 2 product` or `# >=3 sum`
+    pub fn parse(input: &str) -> Result<SymbolRule, String> {
+        let parts: Vec<&str> = input.split_whitespace().collect();
+        let [symbol, count, aggregate] = parts[..] else {
+            return Err(format!("expected `symbol count aggregate`: {input}"));
+        };
+
+        let mut chars = symbol.chars();
+        let (Some(symbol), None) = (chars.next(), chars.next()) else {
+            return Err(format!("symbol must be a single character: {symbol}"));
+        };
+
+        let invalid_count = || format!("invalid count: {count}");
+        let count = match count.strip_prefix(">=") {
+            Some(n) => Count::AtLeast(n.parse().map_err(|_| invalid_count())?),
+            None => Count::Exactly(
+                count
+                    .trim_start_matches('=')
+                    .parse()
+                    .map_err(|_| invalid_count())?,
+            ),
+        };
+
+        let aggregate = match aggregate {
+            "product" => Aggregate::Product,
+            "sum" => Aggregate::Sum,
+            "max" => Aggregate::Max,
+            _ => return Err(format!("invalid aggregate: {aggregate}")),
+        };
+
+        Ok(SymbolRule {
+            symbol,
+            count,
+            aggregate,
+        })
+    }
+
+    pub fn matches(&self, numbers: &[i32]) -> bool {
+        match self.count {
+            Count::Exactly(n) => numbers.len() == n,
+            Count::AtLeast(n) => numbers.len() >= n,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SymbolMatch {
+    pub coord: Coord,
+    pub symbol: char,
+    pub numbers: Vec<i32>,
+    pub value: i64,
+}
+
+impl fmt::Display for SymbolMatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} at {},{}: {:?} -> {}",
+            self.symbol, self.coord.row, self.coord.col, self.numbers, self.value
+        )
+    }
+}
+
+pub fn parse(input: &str) -> Schematic {
+    let mut part_numbers = Vec::new();
+    let mut symbols = HashMap::new();
+    let mut current_number: Option<PartNumber> = None;
+
+    for (row, line) in input.lines().enumerate() {
+        for (col, char) in line.chars().enumerate() {
+            let here = Coord { row, col };
+            match (char, &current_number) {
+                (s, Some(num)) if !s.is_ascii_digit() => {
+                    part_numbers.push(num.clone());
+                    current_number = None;
+
+                    if s != '.' {
+                        symbols.insert(here, s);
+                    }
+                }
+                (s, None) if s.is_ascii_digit() => {
+                    let mut coords = HashSet::new();
+                    coords.insert(here);
+
+                    current_number = Some(PartNumber {
+                        number: i32::try_from(s.to_digit(10).unwrap()).unwrap(),
+                        coords,
+                    });
+                }
+                (s, Some(num)) if s.is_ascii_digit() => {
+                    let mut coords = num.coords.clone();
+                    coords.insert(here);
+                    current_number = Some(PartNumber {
+                        number: num.number * 10 + i32::try_from(s.to_digit(10).unwrap()).unwrap(),
+                        coords,
+                    });
+                }
+                (s, _) if s != '.' => {
+                    symbols.insert(here, s);
+                }
+                _ => {}
+            }
+        }
+        if let Some(num) = current_number.take() {
+            part_numbers.push(num);
+        }
+    }
+
+    let cells = part_numbers
+        .iter()
+        .enumerate()
+        .flat_map(|(id, pn)| pn.coords.iter().map(move |coord| (*coord, id)))
+        .collect();
+
+    Schematic {
+        part_numbers,
+        symbols,
+        cells,
+    }
+}
+
+const GREEN: &str = "\x1b[32m";
+const RED: &str = "\x1b[31m";
+const BOLD: &str = "\x1b[1m";
+const GEAR: &str = "\x1b[1;33m";
+const RESET: &str = "\x1b[0m";
+
+// Part numbers are green when they touch a symbol and red when they don't, gears are
+// yellow and their ratios are listed after the row.
+pub fn render_ansi(input: &str) -> String {
+    let schematic = parse(input);
+    let parts = schematic.numbers_touching_symbols();
+    let gears: HashMap<Coord, i64> = schematic
+        .matching(&SymbolRule::GEAR)
+        .into_iter()
+        .map(|m| (m.coord, m.value))
+        .collect();
+
+    let mut output = String::new();
+    for (row, line) in input.lines().enumerate() {
+        let mut ratios = Vec::new();
+        for (col, char) in line.chars().enumerate() {
+            let here = Coord { row, col };
+            let color = if let Some(id) = schematic.cells.get(&here) {
+                if parts.contains(id) {
+                    GREEN
+                } else {
+                    RED
+                }
+            } else if let Some(ratio) = gears.get(&here) {
+                ratios.push(ratio.to_string());
+                GEAR
+            } else if schematic.symbols.contains_key(&here) {
+                BOLD
+            } else {
+                ""
+            };
+
+            if color.is_empty() {
+                output.push(char);
+            } else {
+                output.push_str(&format!("{color}{char}{RESET}"));
+            }
+        }
+        if !ratios.is_empty() {
+            output.push_str(&format!("  {GEAR}{}{RESET}", ratios.join(" ")));
+        }
+        output.push('\n');
+    }
+    output
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Parsed<'a> = Schematic;
+    type Output1 = i32;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> Schematic {
+        parse(input)
+    }
+
+    fn part1(schematic: &Schematic) -> i32 {
+        schematic
+            .numbers_touching_symbols()
+            .into_iter()
+            .map(|id| schematic.part_numbers[id].number)
+            .sum()
+    }
+
+    fn part2(schematic: &Schematic) -> i64 {
+        schematic.evaluate(&SymbolRule::GEAR)
+        // 31285222: too low
+    }
+}
+
+pub const EXAMPLE: &str = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example1() {
+        assert_eq!(Day03::solve1(EXAMPLE), 4361);
+    }
+
+    #[test]
+    fn example2() {
+        assert_eq!(Day03::solve2(EXAMPLE), 467835);
+    }
+
+    #[test]
+    fn numbers_touching() {
+        let schematic = parse(EXAMPLE);
+        let numbers = |coord| -> Vec<i32> {
+            schematic
+                .numbers_touching(coord)
+                .into_iter()
+                .map(|id| schematic.part_numbers[id].number)
+                .collect()
+        };
+        assert_eq!(numbers(Coord { row: 1, col: 3 }), vec![467, 35]);
+        assert_eq!(numbers(Coord { row: 3, col: 6 }), vec![633]);
+        assert_eq!(numbers(Coord { row: 0, col: 8 }), vec![114]);
+    }
+
+    #[test]
+    fn symbol_rules() {
+        let schematic = parse(EXAMPLE);
+
+        let gears = schematic.matching(&SymbolRule::GEAR);
+        assert_eq!(gears.len(), 2);
+        assert_eq!(gears[0].to_string(), "* at 1,3: [467, 35] -> 16345");
+        assert_eq!(gears[1].numbers, vec![755, 598]);
+
+        let rule = SymbolRule::parse("* >=1 sum").unwrap();
+        assert_eq!(schematic.evaluate(&rule), 467 + 35 + 617 + 755 + 598);
+        let rule = SymbolRule::parse("+ 1 max").unwrap();
+        assert_eq!(schematic.evaluate(&rule), 592);
+
+        assert!(SymbolRule::parse("* two product").is_err());
+        assert!(SymbolRule::parse("** 2 product").is_err());
+    }
+
+    #[test]
+    fn render() {
+        let rendered = render_ansi(EXAMPLE);
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines.len(), 10);
+        assert!(lines[0].starts_with(&format!("{GREEN}4{RESET}")));
+        assert!(lines[0].contains(&format!("{RED}1{RESET}{RED}1{RESET}{RED}4{RESET}")));
+        assert!(lines[1].ends_with(&format!("  {GEAR}16345{RESET}")));
+        assert!(lines[3].contains(&format!("{BOLD}#{RESET}")));
+    }
+}
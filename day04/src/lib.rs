@@ -0,0 +1,421 @@
+use aoc::stream::{self, StreamingSolution};
+use aoc::Solution;
+use std::{
+    collections::VecDeque,
+    fmt::Write,
+    fs,
+    io::{self, BufRead},
+    ops::Range,
+    path::Path,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Scoring {
+    Linear,
+    Doubling,
+    Fibonacci,
+    // points for 1, 2, 3, ... matches
+    Table(Vec<i32>),
+}
+
+impl Scoring {
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name {
+            "linear" => Some(Self::Linear),
+            "doubling" => Some(Self::Doubling),
+            "fibonacci" => Some(Self::Fibonacci),
+            _ => None,
+        }
+    }
+
+    // one `matches points` pair per line starting at 1 match, blank lines and `#` comments
+    // are ignored
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let mut table = Vec::new();
+        for (line_num, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let parsed = line
+                .split_once(char::is_whitespace)
+                .and_then(|(matches, points)| {
+                    Some((matches.parse::<usize>().ok()?, points.trim().parse().ok()?))
+                });
+            match parsed {
+                Some((matches, points)) if matches == table.len() + 1 => table.push(points),
+                Some((matches, _)) => {
+                    return Err(format!(
+                        "line {}: expected points for {} matches, got {matches}",
+                        line_num + 1,
+                        table.len() + 1
+                    ))
+                }
+                None => return Err(format!("line {}: expected `matches points`", line_num + 1)),
+            }
+        }
+        if table.is_empty() {
+            return Err("scoring table is empty".to_string());
+        }
+        Ok(Self::Table(table))
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let input = fs::read_to_string(path)
+            .map_err(|e| format!("could not read {}: {e}", path.display()))?;
+        Self::parse(&input)
+    }
+
+    pub fn score(&self, num_matching: u32) -> Result<i64, String> {
+        if num_matching == 0 {
+            return Ok(0);
+        }
+        let overflow = || format!("the score for {num_matching} matches overflows");
+        match self {
+            Self::Linear => Ok(num_matching.into()),
+            Self::Doubling => 2_i64.checked_pow(num_matching - 1).ok_or_else(overflow),
+            Self::Fibonacci => {
+                let (mut a, mut b) = (0_i64, 1_i64);
+                for _ in 1..num_matching {
+                    (a, b) = (b, a.checked_add(b).ok_or_else(overflow)?);
+                }
+                Ok(b)
+            }
+            Self::Table(table) => table
+                .get(usize::try_from(num_matching).unwrap() - 1)
+                .map(|points| i64::from(*points))
+                .ok_or_else(|| {
+                    format!(
+                        "no score for {num_matching} matches, the table stops at {}",
+                        table.len()
+                    )
+                }),
+        }
+    }
+}
+
+pub struct Card {
+    pub id: i32,
+    pub mine: Vec<i32>,
+    pub winning: Vec<Vec<i32>>,
+}
+
+impl Card {
+    pub fn parse(input: &str) -> Card {
+        let input = input.strip_prefix("Card").unwrap().trim_start();
+        let (id_str, remaining) = input.split_once(':').unwrap();
+        let id: i32 = id_str.parse().unwrap();
+
+        let mut sets = remaining.split('|').map(|numbers_str| {
+            numbers_str
+                .split_whitespace()
+                .map(|num_str| num_str.trim().parse().unwrap())
+                .collect()
+        });
+        let mine: Vec<i32> = sets.next().unwrap();
+        let winning: Vec<Vec<i32>> = sets.collect();
+        assert!(!winning.is_empty(), "card {id} has no winning numbers");
+
+        Card { id, mine, winning }
+    }
+
+    // every winning set is matched separately
+    pub fn num_matching_per_set(&self) -> impl Iterator<Item = u32> + '_ {
+        self.winning.iter().map(|winning| {
+            self.mine
+                .iter()
+                .filter(|m| winning.contains(m))
+                .count()
+                .try_into()
+                .unwrap()
+        })
+    }
+
+    pub fn num_matching(&self) -> u32 {
+        self.num_matching_per_set().sum()
+    }
+
+    pub fn score(&self, scoring: &Scoring) -> Result<i64, String> {
+        self.num_matching_per_set().try_fold(0_i64, |total, n| {
+            let score = scoring
+                .score(n)
+                .map_err(|e| format!("card {}: {e}", self.id))?;
+            total
+                .checked_add(score)
+                .ok_or_else(|| format!("card {}: the score overflows", self.id))
+        })
+    }
+
+    pub fn value(&self) -> i32 {
+        self.score(&Scoring::Doubling)
+            .ok()
+            .and_then(|score| score.try_into().ok())
+            .unwrap_or_else(|| panic!("card {} is worth too many points", self.id))
+    }
+
+    // positions of the cards won by the card at `position`, cut off at the end of the table
+    pub fn spawned(&self, position: usize, num_cards: usize) -> Range<usize> {
+        let num_matching: usize = self.num_matching().try_into().unwrap();
+        let start = (position + 1).min(num_cards);
+        start..(start + num_matching).min(num_cards)
+    }
+}
+
+pub fn total_score(cards: &[Card], scoring: &Scoring) -> Result<i64, String> {
+    cards.iter().try_fold(0_i64, |total, card| {
+        total
+            .checked_add(card.score(scoring)?)
+            .ok_or_else(|| "the total score overflows".to_string())
+    })
+}
+
+pub struct CardNode {
+    pub id: i32,
+    pub spawns: Range<usize>,
+    // the card itself plus everything its copies win, transitively
+    pub produced: u64,
+}
+
+// A card only ever wins later cards, so walking the table backwards every spawned card
+// already knows how many cards it produces.
+pub fn cascade(cards: &[Card]) -> Result<Vec<CardNode>, String> {
+    let spawns: Vec<Range<usize>> = cards
+        .iter()
+        .enumerate()
+        .map(|(position, card)| card.spawned(position, cards.len()))
+        .collect();
+
+    let mut produced: Vec<u64> = vec![0; cards.len()];
+    for position in (0..cards.len()).rev() {
+        produced[position] = produced[spawns[position].clone()]
+            .iter()
+            .try_fold(1_u64, |total, n| total.checked_add(*n))
+            .ok_or_else(|| format!("card {} produces too many cards", cards[position].id))?;
+    }
+
+    Ok(cards
+        .iter()
+        .zip(spawns)
+        .zip(produced)
+        .map(|((card, spawns), produced)| CardNode {
+            id: card.id,
+            spawns,
+            produced,
+        })
+        .collect())
+}
+
+pub fn total_cards(cards: &[Card]) -> Result<u64, String> {
+    cascade(cards)?
+        .iter()
+        .try_fold(0_u64, |total, node| total.checked_add(node.produced))
+        .ok_or_else(|| "the total number of cards overflows".to_string())
+}
+
+pub fn to_dot(cards: &[Card]) -> Result<String, String> {
+    let nodes = cascade(cards)?;
+    let mut output = String::from("digraph cards {\n");
+    for node in &nodes {
+        writeln!(
+            output,
+            "    {} [label=\"Card {}\\n{} cards\"];",
+            node.id, node.id, node.produced
+        )
+        .unwrap();
+        for spawned in node.spawns.clone() {
+            writeln!(output, "    {} -> {};", node.id, nodes[spawned].id).unwrap();
+        }
+    }
+    output.push_str("}\n");
+    Ok(output)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Parsed<'a> = Vec<Card>;
+    type Output1 = i32;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Vec<Card> {
+        input.lines().map(Card::parse).collect()
+    }
+
+    fn part1(cards: &Vec<Card>) -> i32 {
+        cards.iter().map(|card| card.value()).sum()
+    }
+
+    fn part2(cards: &Vec<Card>) -> u64 {
+        total_cards(cards).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
+impl StreamingSolution for Day04 {
+    fn part1_streaming<R: BufRead>(reader: R) -> io::Result<i32> {
+        stream::lines(reader)
+            .map(|line| line.map(|l| Card::parse(&l).value()))
+            .sum()
+    }
+
+    fn part2_streaming<R: BufRead>(reader: R) -> io::Result<u64> {
+        // extra copies won for the upcoming cards, never longer than the largest match count
+        let mut pending: VecDeque<u64> = VecDeque::new();
+        let mut result: u64 = 0;
+        let overflow = || io::Error::new(io::ErrorKind::InvalidData, "too many cards");
+
+        for line in stream::lines(reader) {
+            let card = Card::parse(&line?);
+            let num_card_instances = pending
+                .pop_front()
+                .unwrap_or(0)
+                .checked_add(1)
+                .ok_or_else(overflow)?;
+            result = result
+                .checked_add(num_card_instances)
+                .ok_or_else(overflow)?;
+
+            let num_matching: usize = card.num_matching().try_into().unwrap();
+            if pending.len() < num_matching {
+                pending.resize(num_matching, 0);
+            }
+            for won_card in pending.iter_mut().take(num_matching) {
+                *won_card = won_card
+                    .checked_add(num_card_instances)
+                    .ok_or_else(overflow)?;
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+pub const EXAMPLE: &str = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example1() {
+        assert_eq!(Day04::solve1(EXAMPLE), 13);
+    }
+
+    #[test]
+    fn example2() {
+        assert_eq!(Day04::solve2(EXAMPLE), 30);
+    }
+
+    #[test]
+    fn streaming() {
+        assert_eq!(Day04::part1_streaming(EXAMPLE.as_bytes()).unwrap(), 13);
+        assert_eq!(Day04::part2_streaming(EXAMPLE.as_bytes()).unwrap(), 30);
+
+        let input = format!("{EXAMPLE}\n\n");
+        assert_eq!(Day04::part1_streaming(input.as_bytes()).unwrap(), 13);
+    }
+
+    #[test]
+    fn card_cascade() {
+        let cards = Day04::parse(EXAMPLE);
+        let nodes = cascade(&cards).unwrap();
+        let produced: Vec<u64> = nodes.iter().map(|n| n.produced).collect();
+        assert_eq!(produced, vec![15, 7, 4, 2, 1, 1]);
+        assert_eq!(nodes[0].spawns, 1..5);
+
+        let dot = to_dot(&cards).unwrap();
+        assert!(dot.contains("    1 [label=\"Card 1\\n15 cards\"];\n    1 -> 2;\n"));
+    }
+
+    #[test]
+    fn matches_past_the_end() {
+        let cards = Day04::parse("Card 1: 1 2 3 | 1 2 3\nCard 2: 4 5 | 4 5");
+        let nodes = cascade(&cards).unwrap();
+        assert_eq!(nodes[0].spawns, 1..2);
+        assert_eq!(nodes[1].spawns, 2..2);
+        assert_eq!(Day04::part2(&cards), 3);
+        assert_eq!(
+            Day04::part2_streaming("Card 1: 1 2 3 | 1 2 3\nCard 2: 4 5 | 4 5".as_bytes()).unwrap(),
+            3
+        );
+    }
+
+    #[test]
+    fn doubling_cascade() {
+        // every card wins all the cards after it, so each card doubles the total
+        let table = |num_cards: usize| -> String {
+            (0..num_cards)
+                .map(|position| {
+                    let numbers: Vec<String> = (0..num_cards - 1 - position)
+                        .map(|n| n.to_string())
+                        .collect();
+                    let numbers = numbers.join(" ");
+                    format!("Card {}: {numbers} | 1000 {numbers}\n", position + 1)
+                })
+                .collect()
+        };
+
+        assert_eq!(Day04::solve2(&table(10)), (1 << 10) - 1);
+        assert_eq!(total_cards(&Day04::parse(&table(64))), Ok(u64::MAX));
+
+        let input = table(65);
+        assert_eq!(
+            total_cards(&Day04::parse(&input)),
+            Err("card 1 produces too many cards".to_string())
+        );
+        assert!(Day04::part2_streaming(input.as_bytes()).is_err());
+
+        let input = format!("Card 0: 1 | 2\n{}", table(64));
+        assert_eq!(
+            total_cards(&Day04::parse(&input)),
+            Err("the total number of cards overflows".to_string())
+        );
+    }
+
+    #[test]
+    fn scoring() {
+        let cards = Day04::parse(EXAMPLE);
+        let total = |scoring| total_score(&cards, &scoring).unwrap();
+        assert_eq!(total(Scoring::Doubling), 13);
+        assert_eq!(total(Scoring::Linear), 4 + 2 + 2 + 1);
+        assert_eq!(total(Scoring::Fibonacci), 3 + 1 + 1 + 1);
+        assert_eq!(
+            total(Scoring::parse("1 10\n2 20\n3 30\n4 40").unwrap()),
+            40 + 20 + 20 + 10
+        );
+
+        assert!(Scoring::parse("1 10\n3 30").is_err());
+        assert!(Scoring::parse("# nothing").is_err());
+    }
+
+    #[test]
+    fn scores_out_of_range() {
+        let cards = Day04::parse(EXAMPLE);
+        let short = Scoring::parse("1 10\n2 20").unwrap();
+        assert_eq!(
+            total_score(&cards, &short),
+            Err("card 1: no score for 4 matches, the table stops at 2".to_string())
+        );
+
+        let numbers: Vec<String> = (1..=40).map(|n| n.to_string()).collect();
+        let numbers = numbers.join(" ");
+        let card = Card::parse(&format!("Card 1: {numbers} | {numbers} | {numbers}"));
+        assert_eq!(Scoring::Doubling.score(40), Ok(1 << 39));
+        assert_eq!(card.score(&Scoring::Doubling), Ok(2 << 39));
+        assert!(Scoring::Doubling.score(64).is_err());
+        assert!(Scoring::Fibonacci.score(100).is_err());
+    }
+
+    #[test]
+    fn multiple_winning_sets() {
+        let card = Card::parse("Card 1: 1 2 3 4 | 1 2 | 2 3 4");
+        assert_eq!(card.num_matching_per_set().collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!(card.num_matching(), 5);
+        assert_eq!(card.value(), 2 + 4);
+    }
+}
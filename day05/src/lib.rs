@@ -0,0 +1,821 @@
+use aoc::Solution;
+use std::{
+    cmp::{max, min},
+    collections::HashSet,
+    fmt,
+};
+
+pub fn parse_number_list(input: &str) -> Result<Vec<u64>, String> {
+    input
+        .split_whitespace()
+        .map(|num_str| {
+            num_str
+                .parse()
+                .map_err(|_| format!("invalid number: {num_str}"))
+        })
+        .collect()
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Range {
+    pub start: u64,
+    pub length: u64,
+}
+
+impl Range {
+    // ranges from the almanac go through this so that `end` can't overflow
+    pub fn new(start: u64, length: u64) -> Result<Range, String> {
+        match start.checked_add(length) {
+            Some(_) => Ok(Range { start, length }),
+            None => Err(format!("{start} + {length} runs past {}", u64::MAX)),
+        }
+    }
+
+    pub fn end(&self) -> u64 {
+        self.start + self.length
+    }
+
+    pub fn overlap(&self, other: &Range) -> Option<Range> {
+        if self.start >= other.end() || other.start >= self.end() {
+            None
+        } else {
+            let start = max(self.start, other.start);
+            let end = min(self.end(), other.end());
+            let length = end - start;
+            Some(Range { start, length })
+        }
+    }
+}
+
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}..{}", self.start, self.end())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RangeMapping {
+    pub destination: Range,
+    pub source: Range,
+}
+
+impl RangeMapping {
+    // `destination source length`
+    pub fn parse(input: &str) -> Result<RangeMapping, String> {
+        let &[destination, source, length] = &parse_number_list(input)?[..] else {
+            return Err(format!(
+                "expected `destination source length`, got {input:?}"
+            ));
+        };
+        Ok(RangeMapping {
+            destination: Range::new(destination, length)?,
+            source: Range::new(source, length)?,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Map {
+    pub name: String,
+    pub source: String,
+    pub destination: String,
+    pub ranges: Vec<RangeMapping>,
+}
+
+impl Map {
+    pub fn parse(input: &str) -> Result<Map, String> {
+        let mut lines = input.lines();
+        let header = lines.next().unwrap_or_default();
+        let name = header
+            .strip_suffix(" map:")
+            .ok_or_else(|| format!("expected `X-to-Y map:`, got {header:?}"))?
+            .to_string();
+        let (source, destination) = name
+            .split_once("-to-")
+            .map(|(s, d)| (s.to_string(), d.to_string()))
+            .ok_or_else(|| format!("map name is not `X-to-Y`: {name}"))?;
+
+        let ranges = lines
+            .enumerate()
+            .map(|(i, line)| {
+                RangeMapping::parse(line).map_err(|e| format!("{name} map, line {}: {e}", i + 1))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Map {
+            name,
+            source,
+            destination,
+            ranges,
+        })
+    }
+
+    pub fn get(&self, input_num: u64) -> u64 {
+        for range_mapping in &self.ranges {
+            let (source, dest) = (&range_mapping.source, &range_mapping.destination);
+            if input_num >= source.start && input_num < source.start + source.length {
+                return dest.start + (input_num - source.start);
+            }
+        }
+        input_num
+    }
+
+    pub fn identity(category: &str) -> Map {
+        Map {
+            name: format!("{category}-to-{category}"),
+            source: category.to_string(),
+            destination: category.to_string(),
+            ranges: Vec::new(),
+        }
+        .normalized()
+    }
+
+    // The same mapping with the identity gaps filled in, as non-overlapping source ranges
+    // sorted by start and covering every number.
+    pub fn normalized(&self) -> Map {
+        let mut bounds: Vec<u64> = self
+            .ranges
+            .iter()
+            .flat_map(|r| [r.source.start, r.source.end()])
+            .chain([0, u64::MAX])
+            .collect();
+        bounds.sort_unstable();
+        bounds.dedup();
+
+        let ranges = bounds
+            .windows(2)
+            .map(|w| {
+                let source = Range {
+                    start: w[0],
+                    length: w[1] - w[0],
+                };
+                RangeMapping {
+                    destination: Range {
+                        start: self.get(source.start),
+                        length: source.length,
+                    },
+                    source,
+                }
+            })
+            .collect();
+
+        Map {
+            name: self.name.clone(),
+            source: self.source.clone(),
+            destination: self.destination.clone(),
+            ranges,
+        }
+    }
+
+    // `self` followed by `next` as a single map
+    pub fn compose(&self, next: &Map) -> Map {
+        let next = next.normalized();
+        let mut ranges = Vec::new();
+        for first in self.normalized().ranges {
+            for second in &next.ranges {
+                if let Some(overlap) = first.destination.overlap(&second.source) {
+                    ranges.push(RangeMapping {
+                        source: Range {
+                            start: first.source.start + (overlap.start - first.destination.start),
+                            length: overlap.length,
+                        },
+                        destination: Range {
+                            start: second.destination.start + (overlap.start - second.source.start),
+                            length: overlap.length,
+                        },
+                    });
+                }
+            }
+        }
+        ranges.sort_by_key(|r| r.source.start);
+
+        Map {
+            name: format!("{}-to-{}", self.source, next.destination),
+            source: self.source.clone(),
+            destination: next.destination.clone(),
+            ranges,
+        }
+    }
+
+    // only maps that send every number somewhere different can be inverted
+    pub fn inverse(&self) -> Option<Map> {
+        let mut ranges: Vec<RangeMapping> = self
+            .normalized()
+            .ranges
+            .into_iter()
+            .map(|r| RangeMapping {
+                destination: r.source,
+                source: r.destination,
+            })
+            .collect();
+        ranges.sort_by_key(|r| r.source.start);
+        if ranges
+            .windows(2)
+            .any(|w| w[0].source.end() > w[1].source.start)
+        {
+            return None;
+        }
+
+        Some(Map {
+            name: format!("{}-to-{}", self.destination, self.source),
+            source: self.destination.clone(),
+            destination: self.source.clone(),
+            ranges,
+        })
+    }
+
+    // every number that `get` sends to `output_num`
+    pub fn preimages(&self, output_num: u64) -> Vec<u64> {
+        self.normalized()
+            .ranges
+            .iter()
+            .filter(|r| output_num >= r.destination.start && output_num < r.destination.end())
+            .map(|r| r.source.start + (output_num - r.destination.start))
+            .collect()
+    }
+
+    pub fn min_image(&self, inputs: &[Range]) -> Option<u64> {
+        self.normalized()
+            .ranges
+            .iter()
+            .flat_map(|r| {
+                inputs.iter().filter_map(|input| {
+                    let overlap = r.source.overlap(input)?;
+                    Some(r.destination.start + (overlap.start - r.source.start))
+                })
+            })
+            .min()
+    }
+
+    // numbers that aren't in any source range and map to themselves
+    pub fn identity_ranges(&self) -> Vec<Range> {
+        let mut sources: Vec<&Range> = self.ranges.iter().map(|r| &r.source).collect();
+        sources.sort_by_key(|r| r.start);
+
+        let mut result = Vec::new();
+        let mut covered_until = 0;
+        for source in sources {
+            if source.start > covered_until {
+                result.push(Range {
+                    start: covered_until,
+                    length: source.start - covered_until,
+                });
+            }
+            covered_until = max(covered_until, source.end());
+        }
+        if covered_until < u64::MAX {
+            result.push(Range {
+                start: covered_until,
+                length: u64::MAX - covered_until,
+            });
+        }
+        result
+    }
+
+    pub fn check(&self) -> MapCheck {
+        let mut issues = Vec::new();
+        for (i, first) in self.ranges.iter().enumerate() {
+            for (j, second) in self.ranges.iter().enumerate().skip(i + 1) {
+                if let Some(overlap) = first.source.overlap(&second.source) {
+                    issues.push(Issue::OverlappingSources(i, j, overlap));
+                }
+                if let Some(overlap) = first.destination.overlap(&second.destination) {
+                    issues.push(Issue::OverlappingDestinations(i, j, overlap));
+                }
+            }
+        }
+
+        let identity = self.identity_ranges();
+        for (i, range_mapping) in self.ranges.iter().enumerate() {
+            for overlap in identity
+                .iter()
+                .filter_map(|gap| gap.overlap(&range_mapping.destination))
+            {
+                issues.push(Issue::IdentityCollision(i, overlap));
+            }
+        }
+
+        // only the gaps between source ranges, everything around them is identity anyway
+        let lowest = self.ranges.iter().map(|r| r.source.start).min();
+        let highest = self.ranges.iter().map(|r| r.source.end()).max();
+        let gaps = identity
+            .into_iter()
+            .filter(|gap| Some(gap.start) > lowest && Some(gap.start) < highest)
+            .collect();
+
+        MapCheck {
+            name: self.name.clone(),
+            issues,
+            gaps,
+        }
+    }
+
+    pub fn get_range(&self, input_range: Range) -> Vec<Range> {
+        // println!("Current mapping: {}", self.name);
+        let mut result_ranges = Vec::new();
+        let mut remaining_ranges = HashSet::new();
+        remaining_ranges.insert(input_range);
+
+        for range_mapping in &self.ranges {
+            // println!("Processing range mapping: {:?}", &range_mapping);
+            for current_range in remaining_ranges.clone() {
+                // println!("  - Processing remaining range: {:?}", &current_range);
+                // println!("  - range mapping source: {:?}", &range_mapping.source);
+                if let Some(overlap) = range_mapping.source.overlap(&current_range) {
+                    remaining_ranges.remove(&current_range);
+
+                    // println!("  - Overlap found: {:?} - {}", &overlap, &overlap.end());
+                    if overlap.start > current_range.start {
+                        remaining_ranges.insert(Range {
+                            start: current_range.start,
+                            length: overlap.start - current_range.start,
+                        });
+                    }
+
+                    if overlap.end() < current_range.end() {
+                        remaining_ranges.insert(Range {
+                            start: overlap.end(),
+                            length: current_range.end() - overlap.end(),
+                        });
+                    }
+
+                    let destination_range = Range {
+                        start: range_mapping.destination.start
+                            + (overlap.start - range_mapping.source.start),
+                        length: overlap.length,
+                    };
+                    result_ranges.push(destination_range);
+                }
+            }
+            // println!("- remaining ranges: {:?}", &remaining);
+            // println!("- result ranges: {:?}", &result);
+        }
+        for remaining_range in remaining_ranges {
+            result_ranges.push(remaining_range);
+        }
+
+        result_ranges
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Issue {
+    // indices into `Map::ranges`
+    OverlappingSources(usize, usize, Range),
+    OverlappingDestinations(usize, usize, Range),
+    // numbers that a range maps to and that also map to themselves
+    IdentityCollision(usize, Range),
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::OverlappingSources(i, j, overlap) => {
+                write!(
+                    f,
+                    "ranges {} and {} overlap on sources {overlap}",
+                    i + 1,
+                    j + 1
+                )
+            }
+            Self::OverlappingDestinations(i, j, overlap) => write!(
+                f,
+                "ranges {} and {} overlap on destinations {overlap}",
+                i + 1,
+                j + 1
+            ),
+            Self::IdentityCollision(i, overlap) => write!(
+                f,
+                "range {} maps onto {overlap}, which also maps to itself",
+                i + 1
+            ),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct MapCheck {
+    pub name: String,
+    pub issues: Vec<Issue>,
+    pub gaps: Vec<Range>,
+}
+
+impl MapCheck {
+    pub fn is_ok(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+impl fmt::Display for MapCheck {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: ", self.name)?;
+        if self.is_ok() {
+            write!(f, "ok")?;
+        } else {
+            write!(f, "{} issues", self.issues.len())?;
+        }
+        for issue in &self.issues {
+            write!(f, "\n  {issue}")?;
+        }
+        for gap in &self.gaps {
+            write!(f, "\n  identity gap {gap}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct PuzzleInput {
+    pub seeds_1: Vec<u64>,
+    pub seeds_2: Vec<Range>,
+    pub maps: Vec<Map>,
+}
+
+impl PuzzleInput {
+    pub fn parse(input: &str) -> Result<PuzzleInput, String> {
+        let mut parts = input.split("\n\n").filter(|part| !part.trim().is_empty());
+
+        let seeds_1 = parts
+            .next()
+            .and_then(|s| s.strip_prefix("seeds: "))
+            .ok_or_else(|| "expected `seeds: ...` first".to_string())
+            .and_then(parse_number_list)
+            .map_err(|e| format!("seeds: {e}"))?;
+
+        let seeds_2 = seeds_1
+            .chunks(2)
+            .map(|nums| match nums {
+                &[start, length] => Range::new(start, length),
+                _ => Err("expected pairs of start and length".to_string()),
+            })
+            .collect::<Result<_, _>>()
+            .map_err(|e| format!("seeds: {e}"))?;
+
+        let maps = parts.map(Map::parse).collect::<Result<_, _>>()?;
+
+        Ok(PuzzleInput {
+            seeds_1,
+            seeds_2,
+            maps,
+        })
+    }
+
+    // the maps leading from one category to another, which must be connected by exactly
+    // one chain of maps
+    pub fn route(&self, from: &str, to: &str) -> Result<Vec<&Map>, RouteError> {
+        for category in [from, to] {
+            if !self
+                .maps
+                .iter()
+                .any(|m| m.source == category || m.destination == category)
+            {
+                return Err(RouteError::UnknownCategory(category.to_string()));
+            }
+        }
+
+        let mut routes = Vec::new();
+        self.find_routes(from, to, &mut Vec::new(), &mut routes);
+        match routes.len() {
+            0 => Err(RouteError::Missing {
+                from: from.to_string(),
+                to: to.to_string(),
+            }),
+            1 => Ok(routes.pop().unwrap()),
+            n => Err(RouteError::Ambiguous {
+                from: from.to_string(),
+                to: to.to_string(),
+                routes: n,
+            }),
+        }
+    }
+
+    fn find_routes<'a>(
+        &'a self,
+        current: &str,
+        to: &str,
+        route: &mut Vec<&'a Map>,
+        routes: &mut Vec<Vec<&'a Map>>,
+    ) {
+        if current == to {
+            routes.push(route.clone());
+            return;
+        }
+        for map in self.maps.iter().filter(|m| m.source == current) {
+            // don't go round in circles
+            if map.destination == current || route.iter().any(|m| m.source == map.destination) {
+                continue;
+            }
+            route.push(map);
+            self.find_routes(&map.destination, to, route, routes);
+            route.pop();
+        }
+    }
+
+    pub fn convert(&self, from: &str, to: &str, value: u64) -> Result<u64, RouteError> {
+        Ok(convert_along(&self.route(from, to)?, value))
+    }
+
+    pub fn convert_range(
+        &self,
+        from: &str,
+        to: &str,
+        range: Range,
+    ) -> Result<Vec<Range>, RouteError> {
+        Ok(convert_ranges_along(&self.route(from, to)?, vec![range]))
+    }
+
+    pub fn compose(&self, from: &str, to: &str) -> Result<Map, RouteError> {
+        Ok(self
+            .route(from, to)?
+            .iter()
+            .fold(Map::identity(from), |composed, map| composed.compose(map)))
+    }
+
+    // walks the route backwards, e.g. every seed that ends up at a given location
+    pub fn preimages(&self, from: &str, to: &str, value: u64) -> Result<Vec<u64>, RouteError> {
+        let mut values = vec![value];
+        for map in self.route(from, to)?.iter().rev() {
+            values = values.iter().flat_map(|v| map.preimages(*v)).collect();
+        }
+        values.sort_unstable();
+        values.dedup();
+        Ok(values)
+    }
+
+    pub fn check(&self) -> Vec<MapCheck> {
+        self.maps.iter().map(Map::check).collect()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum RouteError {
+    UnknownCategory(String),
+    Missing {
+        from: String,
+        to: String,
+    },
+    Ambiguous {
+        from: String,
+        to: String,
+        routes: usize,
+    },
+}
+
+impl fmt::Display for RouteError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::UnknownCategory(category) => write!(f, "unknown category: {category}"),
+            Self::Missing { from, to } => write!(f, "no maps lead from {from} to {to}"),
+            Self::Ambiguous { from, to, routes } => {
+                write!(f, "{routes} different routes lead from {from} to {to}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RouteError {}
+
+pub fn convert_along(route: &[&Map], value: u64) -> u64 {
+    route.iter().fold(value, |value, map| map.get(value))
+}
+
+pub fn convert_ranges_along(route: &[&Map], ranges: Vec<Range>) -> Vec<Range> {
+    route.iter().fold(ranges, |ranges, map| {
+        ranges
+            .into_iter()
+            .flat_map(|range| map.get_range(range))
+            .collect()
+    })
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Parsed<'a> = PuzzleInput;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> PuzzleInput {
+        PuzzleInput::parse(input).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    fn part1(puzzle: &PuzzleInput) -> u64 {
+        let route = puzzle
+            .route("seed", "location")
+            .unwrap_or_else(|e| panic!("{e}"));
+        puzzle
+            .seeds_1
+            .iter()
+            .map(|&seed| convert_along(&route, seed))
+            .min()
+            .unwrap()
+    }
+
+    fn part2(puzzle: &PuzzleInput) -> u64 {
+        let route = puzzle
+            .route("seed", "location")
+            .unwrap_or_else(|e| panic!("{e}"));
+        convert_ranges_along(&route, puzzle.seeds_2.clone())
+            .iter()
+            .map(|range| range.start)
+            .min()
+            .unwrap()
+    }
+}
+
+pub const EXAMPLE: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example1() {
+        assert_eq!(Day05::solve1(EXAMPLE), 35);
+    }
+
+    #[test]
+    fn example2() {
+        assert_eq!(Day05::solve2(EXAMPLE), 46);
+    }
+
+    #[test]
+    fn range() {
+        let r1 = Range {
+            start: 20,
+            length: 30,
+        };
+        let r2 = Range {
+            start: 15,
+            length: 10,
+        };
+        assert_eq!(
+            r1.overlap(&r2),
+            Some(Range {
+                start: 20,
+                length: 5
+            })
+        );
+    }
+
+    #[test]
+    fn category_routes() {
+        let puzzle = PuzzleInput::parse(EXAMPLE).unwrap();
+        assert_eq!(puzzle.convert("soil", "humidity", 81).unwrap(), 78);
+        assert_eq!(puzzle.convert("seed", "seed", 79).unwrap(), 79);
+        assert_eq!(
+            puzzle.convert("location", "seed", 82),
+            Err(RouteError::Missing {
+                from: "location".to_string(),
+                to: "seed".to_string()
+            })
+        );
+        assert_eq!(
+            puzzle.route("seed", "gold").unwrap_err(),
+            RouteError::UnknownCategory("gold".to_string())
+        );
+
+        // the order of the maps in the file doesn't matter
+        let mut sections: Vec<&str> = EXAMPLE.split("\n\n").collect();
+        sections[1..].reverse();
+        assert_eq!(Day05::solve1(&sections.join("\n\n")), 35);
+
+        let mut duplicated = sections.clone();
+        duplicated.push("seed-to-soil map:\n1 2 3");
+        let puzzle = PuzzleInput::parse(&duplicated.join("\n\n")).unwrap();
+        assert!(matches!(
+            puzzle.route("seed", "location"),
+            Err(RouteError::Ambiguous { routes: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn composition() {
+        let puzzle = PuzzleInput::parse(EXAMPLE).unwrap();
+        let composed = puzzle.compose("seed", "location").unwrap();
+        assert_eq!(composed.name, "seed-to-location");
+        for seed in (0..200).chain([u64::MAX - 1]) {
+            assert_eq!(
+                composed.get(seed),
+                puzzle.convert("seed", "location", seed).unwrap()
+            );
+        }
+        assert_eq!(composed.min_image(&puzzle.seeds_2), Some(46));
+
+        let composed = puzzle.compose("soil", "humidity").unwrap();
+        assert_eq!(composed.get(81), 78);
+    }
+
+    #[test]
+    fn inversion() {
+        let puzzle = PuzzleInput::parse(EXAMPLE).unwrap();
+        let inverse = puzzle
+            .compose("seed", "location")
+            .unwrap()
+            .inverse()
+            .unwrap();
+        assert_eq!(inverse.name, "location-to-seed");
+        for location in 0..200 {
+            assert_eq!(
+                puzzle.preimages("seed", "location", location).unwrap(),
+                vec![inverse.get(location)]
+            );
+        }
+        assert_eq!(inverse.get(82), 79);
+
+        let squashed = Map::parse("a-to-b map:\n0 10 5").unwrap();
+        assert!(squashed.inverse().is_none());
+        assert_eq!(squashed.preimages(3), vec![3, 13]);
+        assert_eq!(squashed.preimages(12), vec![]);
+    }
+
+    #[test]
+    fn check() {
+        assert!(PuzzleInput::parse(EXAMPLE)
+            .unwrap()
+            .check()
+            .iter()
+            .all(|c| c.is_ok()));
+
+        let map = Map::parse("a-to-b map:\n0 10 5\n100 12 5\n20 30 5").unwrap();
+        let check = map.check();
+        let range = |start, length| Range { start, length };
+        assert_eq!(
+            check.issues,
+            vec![
+                Issue::OverlappingSources(0, 1, range(12, 3)),
+                Issue::IdentityCollision(0, range(0, 5)),
+                Issue::IdentityCollision(1, range(100, 5)),
+                Issue::IdentityCollision(2, range(20, 5)),
+            ]
+        );
+        assert_eq!(check.gaps, vec![range(17, 13)]);
+        assert!(Map::parse("a-to-b map:\n0 10 5\n2 20 5")
+            .unwrap()
+            .check()
+            .issues
+            .contains(&Issue::OverlappingDestinations(0, 1, range(2, 3))));
+        assert_eq!(
+            check.to_string(),
+            "\
+a-to-b: 4 issues
+  ranges 1 and 2 overlap on sources 12..15
+  range 1 maps onto 0..5, which also maps to itself
+  range 2 maps onto 100..105, which also maps to itself
+  range 3 maps onto 20..25, which also maps to itself
+  identity gap 17..30"
+        );
+    }
+
+    #[test]
+    fn malformed_almanacs() {
+        assert_eq!(
+            Map::parse("a-to-b map:\n0 18446744073709551610 10").unwrap_err(),
+            "a-to-b map, line 1: 18446744073709551610 + 10 runs past 18446744073709551615"
+        );
+        assert_eq!(
+            Map::parse("a-to-b map:\n0 10 5\n0 10").unwrap_err(),
+            "a-to-b map, line 2: expected `destination source length`, got \"0 10\""
+        );
+        assert!(Map::parse("a-to-b map:\n0 x 5").is_err());
+        assert!(Map::parse("a to b map:\n0 10 5").is_err());
+        assert!(Map::parse("a-to-b map:\n0 18446744073709551605 10").is_ok());
+
+        assert!(PuzzleInput::parse("seeds: 1 2 3").is_err());
+        assert!(PuzzleInput::parse("seeds: 18446744073709551615 2").is_err());
+        assert!(PuzzleInput::parse(&format!("{EXAMPLE}\n\n")).is_ok());
+    }
+}
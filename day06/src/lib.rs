@@ -0,0 +1,407 @@
+use aoc::Solution;
+use std::{iter::zip, ops::RangeInclusive};
+
+pub fn parse_number_list(input: &str) -> Vec<u64> {
+    input
+        .split_whitespace()
+        .map(|num_str| num_str.parse().unwrap())
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Physics {
+    // the boat gains `acceleration` mm/ms of speed for every ms the button is held
+    Linear { acceleration: u64 },
+    Capped { acceleration: u64, max_speed: u64 },
+    // the boat loses `friction` mm/ms of speed for every ms it moves
+    Friction { acceleration: u64, friction: u64 },
+}
+
+impl Physics {
+    pub const PUZZLE: Physics = Physics::Linear { acceleration: 1 };
+
+    // `linear <acceleration>`, `capped <acceleration> <max speed>` or
+    // `friction <acceleration> <friction>`
+    pub fn parse(input: &str) -> Result<Physics, String> {
+        let parts: Vec<&str> = input.split_whitespace().collect();
+        let numbers: Vec<u64> = parts
+            .iter()
+            .skip(1)
+            .map(|n| n.parse().map_err(|_| format!("invalid number: {n}")))
+            .collect::<Result<_, _>>()?;
+        match (parts.first(), &numbers[..]) {
+            (Some(&"linear"), &[acceleration]) => Ok(Physics::Linear { acceleration }),
+            (Some(&"capped"), &[acceleration, max_speed]) => Ok(Physics::Capped {
+                acceleration,
+                max_speed,
+            }),
+            (Some(&"friction"), &[acceleration, friction]) => Ok(Physics::Friction {
+                acceleration,
+                friction,
+            }),
+            _ => Err(format!("invalid physics: {input}")),
+        }
+    }
+
+    pub fn distance(&self, hold_time: u64, race_time: u64) -> u128 {
+        let moving_time = u128::from(race_time - hold_time);
+        match *self {
+            Physics::Linear { acceleration } => {
+                (u128::from(acceleration) * u128::from(hold_time)).saturating_mul(moving_time)
+            }
+            Physics::Capped {
+                acceleration,
+                max_speed,
+            } => {
+                (u128::from(acceleration) * u128::from(hold_time)).min(u128::from(max_speed))
+                    * moving_time
+            }
+            Physics::Friction {
+                acceleration,
+                friction,
+            } => {
+                let speed = u128::from(acceleration) * u128::from(hold_time);
+                let friction = u128::from(friction);
+                // only the moments the boat still has speed count
+                let moving_time = match friction {
+                    0 => moving_time,
+                    _ => moving_time.min(speed.div_ceil(friction)),
+                };
+                match moving_time.checked_mul(speed) {
+                    // friction never takes away more than half of this
+                    Some(total) => {
+                        total - friction * moving_time * moving_time.saturating_sub(1) / 2
+                    }
+                    None => u128::MAX,
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Race {
+    pub time: u64,
+    pub distance: u64,
+}
+
+impl Race {
+    pub fn num_ways_to_win_bruteforce(&self) -> usize {
+        (0..self.time + 1)
+            .filter(|hold_time| {
+                let remaining_time = self.time - hold_time;
+                let travel_speed = hold_time;
+                let distance_traveled = remaining_time * travel_speed;
+                distance_traveled > self.distance
+            })
+            .count()
+    }
+
+    pub fn beats_record(&self, hold_time: u64) -> bool {
+        u128::from(hold_time) * u128::from(self.time - hold_time) > u128::from(self.distance)
+    }
+
+    // h * (T - h) > D between the roots of h^2 - T * h + D, which are symmetric around T / 2
+    pub fn winning_hold_times(&self) -> Option<RangeInclusive<u64>> {
+        let (time, distance) = (u128::from(self.time), u128::from(self.distance));
+        let discriminant = (time * time).checked_sub(4 * distance)?;
+        let mut low = u64::try_from((time - discriminant.isqrt()) / 2).unwrap();
+
+        // the integer square root is off by less than one, so this is a step or two at most
+        while low > 0 && self.beats_record(low - 1) {
+            low -= 1;
+        }
+        while low <= self.time / 2 && !self.beats_record(low) {
+            low += 1;
+        }
+        if low > self.time / 2 {
+            return None;
+        }
+        Some(low..=self.time - low)
+    }
+
+    fn linear_hold_times(&self, acceleration: u64) -> Option<RangeInclusive<u64>> {
+        if acceleration == 0 {
+            return None;
+        }
+        // a * x > D exactly when x > D / a
+        let scaled = Race {
+            time: self.time,
+            distance: self.distance / acceleration,
+        };
+        scaled.winning_hold_times()
+    }
+
+    // Every model rises to a single peak and falls after it, so the winning hold times are
+    // always one interval.
+    pub fn winning_hold_times_with(&self, physics: &Physics) -> Option<RangeInclusive<u64>> {
+        match *physics {
+            Physics::Linear { acceleration } => self.linear_hold_times(acceleration),
+            Physics::Capped {
+                acceleration,
+                max_speed,
+            } => {
+                if acceleration == 0 || max_speed == 0 {
+                    return None;
+                }
+                // up to the cap this is linear physics, after it the boat goes max_speed
+                let cap = (max_speed / acceleration).min(self.time);
+                let below = self
+                    .linear_hold_times(acceleration)
+                    .map(|hold_times| *hold_times.start()..=(*hold_times.end()).min(cap))
+                    .filter(|hold_times| !hold_times.is_empty());
+                // M * (T - h) > D exactly when h < T - D / M
+                let above = self
+                    .time
+                    .checked_sub(self.distance / max_speed + 1)
+                    .filter(|last| *last > cap)
+                    .map(|last| cap + 1..=last);
+                match (below, above) {
+                    (Some(below), Some(above)) => Some(*below.start()..=*above.end()),
+                    (below, above) => below.or(above),
+                }
+            }
+            Physics::Friction { .. } => {
+                let distance = |hold_time| physics.distance(hold_time, self.time);
+                let beats = |hold_time| distance(hold_time) > u128::from(self.distance);
+
+                let peak = partition_point(0, self.time, |h| distance(h + 1) > distance(h));
+                if !beats(peak) {
+                    return None;
+                }
+                let start = partition_point(0, peak, |h| !beats(h));
+                let end = partition_point(peak, self.time, |h| beats(h + 1));
+                Some(start..=end)
+            }
+        }
+    }
+
+    pub fn num_ways_to_win_with(&self, physics: &Physics) -> u64 {
+        self.winning_hold_times_with(physics)
+            .map_or(0, |hold_times| hold_times.end() - hold_times.start() + 1)
+    }
+
+    pub fn num_ways_to_win(&self) -> u64 {
+        self.winning_hold_times()
+            .map_or(0, |hold_times| hold_times.end() - hold_times.start() + 1)
+    }
+}
+
+// the first value in low..high for which `pred` is false, or high if there is none, for a
+// `pred` that is true up to some point and false after it
+fn partition_point(mut low: u64, mut high: u64, pred: impl Fn(u64) -> bool) -> u64 {
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if pred(mid) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+pub fn parse_1(input: &str) -> Vec<Race> {
+    let mut lines = input.lines();
+    let times: Vec<u64> = lines
+        .next()
+        .and_then(|line| line.strip_prefix("Time: "))
+        .map(parse_number_list)
+        .unwrap();
+    let distances: Vec<u64> = lines
+        .next()
+        .and_then(|line| line.strip_prefix("Distance: "))
+        .map(parse_number_list)
+        .unwrap();
+
+    zip(times, distances)
+        .map(|(time, distance)| Race { time, distance })
+        .collect()
+}
+
+pub fn parse_2(input: &str) -> Race {
+    let mut lines = input.lines();
+    let time: u64 = lines
+        .next()
+        .and_then(|line| line.strip_prefix("Time: "))
+        .map(|line| line.replace(' ', ""))
+        .map(|line| line.parse().unwrap())
+        .unwrap();
+    let distance: u64 = lines
+        .next()
+        .and_then(|line| line.strip_prefix("Distance: "))
+        .map(|line| line.replace(' ', ""))
+        .map(|line| line.parse().unwrap())
+        .unwrap();
+    Race { time, distance }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Parsed<'a> = (Vec<Race>, Race);
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> (Vec<Race>, Race) {
+        (parse_1(input), parse_2(input))
+    }
+
+    fn part1((races, _): &(Vec<Race>, Race)) -> u64 {
+        races.iter().map(|r| r.num_ways_to_win()).product()
+    }
+
+    fn part2((_, race): &(Vec<Race>, Race)) -> u64 {
+        race.num_ways_to_win()
+    }
+}
+
+pub const EXAMPLE: &str = "\
+Time:      7  15   30
+Distance:  9  40  200";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example1() {
+        assert_eq!(Day06::solve1(EXAMPLE), 288);
+    }
+
+    #[test]
+    fn example2() {
+        assert_eq!(Day06::solve2(EXAMPLE), 71503);
+    }
+
+    #[test]
+    fn closed_form_matches_bruteforce() {
+        for time in 0..60 {
+            for distance in 0..time * time / 4 + 2 {
+                let race = Race { time, distance };
+                assert_eq!(
+                    race.num_ways_to_win(),
+                    race.num_ways_to_win_bruteforce() as u64,
+                    "{race:?}"
+                );
+            }
+        }
+        let race = Race {
+            time: 30,
+            distance: 200,
+        };
+        assert_eq!(race.winning_hold_times(), Some(11..=19));
+    }
+
+    #[test]
+    fn huge_races() {
+        for (time, distance) in [
+            (u64::MAX, u64::MAX),
+            (u64::MAX, 0),
+            (u64::MAX - 1, 12345678901234567890),
+            (1 << 33, u64::MAX),
+            (1 << 33, 1 << 63),
+        ] {
+            let race = Race { time, distance };
+            let hold_times = race.winning_hold_times().unwrap();
+            let (low, high) = (*hold_times.start(), *hold_times.end());
+            assert!(
+                race.beats_record(low) && race.beats_record(high),
+                "{race:?}"
+            );
+            assert!(low == 0 || !race.beats_record(low - 1), "{race:?}");
+            assert!(high == time || !race.beats_record(high + 1), "{race:?}");
+        }
+        let race = Race {
+            time: 1 << 32,
+            distance: u64::MAX,
+        };
+        assert_eq!(race.winning_hold_times(), None);
+    }
+
+    fn bruteforce(race: &Race, physics: &Physics) -> Option<RangeInclusive<u64>> {
+        let mut winning =
+            (0..=race.time).filter(|h| physics.distance(*h, race.time) > u128::from(race.distance));
+        let start = winning.next()?;
+        Some(start..=winning.next_back().unwrap_or(start))
+    }
+
+    #[test]
+    fn physics_models() {
+        let (races, _) = Day06::parse(EXAMPLE);
+        for race in &races {
+            assert_eq!(
+                race.num_ways_to_win_with(&Physics::PUZZLE),
+                race.num_ways_to_win()
+            );
+        }
+
+        for time in 0..40 {
+            for distance in (0..time * time).step_by(7) {
+                let race = Race { time, distance };
+                for a in 0..4 {
+                    for b in 0..5 {
+                        for physics in [
+                            Physics::Linear { acceleration: a },
+                            Physics::Capped {
+                                acceleration: a,
+                                max_speed: b * 3,
+                            },
+                            Physics::Friction {
+                                acceleration: a,
+                                friction: b,
+                            },
+                        ] {
+                            assert_eq!(
+                                race.winning_hold_times_with(&physics),
+                                bruteforce(&race, &physics),
+                                "{race:?} {physics:?}"
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        let race = Race {
+            time: 10,
+            distance: 15,
+        };
+        let capped = Physics::parse("capped 1 3").unwrap();
+        assert_eq!(capped.distance(5, 10), 15);
+        assert_eq!(race.winning_hold_times_with(&capped), Some(2..=4));
+        let friction = Physics::parse("friction 2 1").unwrap();
+        assert_eq!(friction.distance(3, 10), 6 + 5 + 4 + 3 + 2 + 1);
+        assert_eq!(friction.distance(9, 10), 18);
+        assert!(Physics::parse("capped 1").is_err());
+    }
+
+    #[test]
+    fn huge_races_with_physics() {
+        let race = Race {
+            time: u64::MAX,
+            distance: u64::MAX,
+        };
+        for physics in [
+            Physics::Capped {
+                acceleration: 3,
+                max_speed: 1 << 40,
+            },
+            Physics::Friction {
+                acceleration: 3,
+                friction: 1,
+            },
+            Physics::Friction {
+                acceleration: u64::MAX,
+                friction: 1 << 20,
+            },
+        ] {
+            let hold_times = race.winning_hold_times_with(&physics).unwrap();
+            let (low, high) = (*hold_times.start(), *hold_times.end());
+            let beats = |h| physics.distance(h, race.time) > u128::from(race.distance);
+            assert!(beats(low) && beats(high), "{physics:?}");
+            assert!(low == 0 || !beats(low - 1), "{physics:?}");
+            assert!(high == race.time || !beats(high + 1), "{physics:?}");
+        }
+    }
+}
@@ -0,0 +1,794 @@
+use aoc::stream::{self, StreamingSolution};
+use aoc::Solution;
+use rayon::prelude::*;
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    fmt,
+    io::{self, BufRead},
+    ops::Range,
+};
+
+pub fn value_counts<T: std::marker::Copy + std::cmp::Eq + PartialEq + std::hash::Hash>(
+    items: &[T],
+) -> HashMap<T, usize> {
+    items.iter().copied().fold(HashMap::new(), |mut map, val| {
+        *map.entry(val).or_default() += 1;
+        map
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+    Flush,
+    // card counts that don't have a name with five cards, most common first
+    Other(Vec<usize>),
+}
+
+impl HandType {
+    // how often each card occurs, most common first; the single cards don't change the name
+    pub fn from_counts(counts: &[usize]) -> HandType {
+        let groups = counts
+            .iter()
+            .position(|count| *count == 1)
+            .unwrap_or(counts.len());
+        match counts[..groups] {
+            [] => HandType::HighCard,
+            [2] => HandType::OnePair,
+            [2, 2] => HandType::TwoPair,
+            [3] => HandType::ThreeOfAKind,
+            [3, 2] => HandType::FullHouse,
+            [4] => HandType::FourOfAKind,
+            [5] => HandType::FiveOfAKind,
+            _ => HandType::Other(counts.to_vec()),
+        }
+    }
+
+    // `32` for a full house, `flush` for a flush
+    pub fn parse(input: &str, hand_size: usize) -> Result<HandType, String> {
+        if input == "flush" {
+            return Ok(HandType::Flush);
+        }
+        input
+            .chars()
+            .map(|c| c.to_digit(10).filter(|d| *d > 0).map(|d| d as usize))
+            .collect::<Option<Vec<usize>>>()
+            .filter(|counts| counts.iter().sum::<usize>() == hand_size)
+            .filter(|counts| counts.windows(2).all(|w| w[0] >= w[1]))
+            .map(|counts| HandType::from_counts(&counts))
+            .ok_or_else(|| format!("invalid hand type: {input}"))
+    }
+}
+
+impl fmt::Display for HandType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HandType::HighCard => write!(f, "High card"),
+            HandType::OnePair => write!(f, "One pair"),
+            HandType::TwoPair => write!(f, "Two pair"),
+            HandType::ThreeOfAKind => write!(f, "Three of a kind"),
+            HandType::FullHouse => write!(f, "Full house"),
+            HandType::FourOfAKind => write!(f, "Four of a kind"),
+            HandType::FiveOfAKind => write!(f, "Five of a kind"),
+            HandType::Flush => write!(f, "Flush"),
+            HandType::Other(counts) => match counts[..] {
+                [n] => write!(f, "{n} of a kind"),
+                _ => {
+                    let counts: Vec<String> = counts.iter().map(|c| c.to_string()).collect();
+                    write!(f, "{}", counts.join("+"))
+                }
+            },
+        }
+    }
+}
+
+// Every way to split `n` cards into groups of at most `max` cards, largest group first.
+fn partitions(n: usize, max: usize) -> Vec<Vec<usize>> {
+    if n == 0 {
+        return vec![Vec::new()];
+    }
+    (1..=max.min(n))
+        .flat_map(|first| {
+            partitions(n - first, first)
+                .into_iter()
+                .map(move |mut rest| {
+                    rest.insert(0, first);
+                    rest
+                })
+        })
+        .collect()
+}
+
+// Every way to play `num_wildcards` wildcards: how many join each of `num_groups` groups of
+// regular cards, most first, and the sizes of the groups of new cards the rest form.
+fn wildcard_plays(
+    num_groups: usize,
+    num_wildcards: usize,
+    max_new_groups: usize,
+) -> Vec<(Vec<usize>, Vec<usize>)> {
+    if num_groups == 0 {
+        return partitions(num_wildcards, num_wildcards)
+            .into_iter()
+            .filter(|new_groups| new_groups.len() <= max_new_groups)
+            .map(|new_groups| (Vec::new(), new_groups))
+            .collect();
+    }
+    (0..=num_wildcards)
+        .rev()
+        .flat_map(|joined| {
+            wildcard_plays(num_groups - 1, num_wildcards - joined, max_new_groups)
+                .into_iter()
+                .map(move |(mut rest, new_groups)| {
+                    rest.insert(0, joined);
+                    (rest, new_groups)
+                })
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone)]
+pub struct RuleSet {
+    // weakest first
+    pub card_order: Vec<char>,
+    // stand in for whichever card makes the best hand, and are weaker than every card in
+    // `card_order`; weakest first
+    pub wildcards: Vec<char>,
+    pub hand_size: usize,
+    // cards are written as rank and suit, e.g. `Ah`
+    pub suited: bool,
+    // weakest first
+    pub hand_types: Vec<HandType>,
+}
+
+// so that `ProcessedHand::sort_key` fits into a u128
+pub const MAX_HAND_SIZE: usize = 15;
+
+impl RuleSet {
+    pub fn standard() -> RuleSet {
+        RuleSet {
+            card_order: "23456789TJQKA".chars().collect(),
+            wildcards: Vec::new(),
+            hand_size: 5,
+            suited: false,
+            hand_types: RuleSet::default_hand_types(5, false),
+        }
+    }
+
+    pub fn jokers() -> RuleSet {
+        RuleSet {
+            card_order: "23456789TQKA".chars().collect(),
+            wildcards: vec!['J'],
+            ..RuleSet::standard()
+        }
+    }
+
+    // Hands with more of the same card are better, which is the same as comparing the
+    // sorted counts: [5] > [4,1] > [3,2] > [3,1,1] > [2,2,1] > [2,1,1,1] > [1,1,1,1,1].
+    // A flush goes right below the full house, like in poker.
+    pub fn default_hand_types(hand_size: usize, flushes: bool) -> Vec<HandType> {
+        let mut counts = partitions(hand_size, hand_size);
+        counts.sort();
+        let mut hand_types: Vec<HandType> =
+            counts.iter().map(|c| HandType::from_counts(c)).collect();
+        if flushes {
+            let position = counts
+                .iter()
+                .position(|c| c[..] >= [3, 2][..])
+                .unwrap_or(counts.len());
+            hand_types.insert(position, HandType::Flush);
+        }
+        hand_types
+    }
+
+    // One setting per line, blank lines and `#` comments are ignored:
+    //
+    //   cards 23456789TQKA
+    //   wildcards J
+    //   hand_size 6
+    //   flushes
+    //   hand_types 11111 2111 221 311 flush 32 41 5
+    //
+    // Everything that isn't set is the same as in the standard rules, and `flushes` means
+    // cards are written with their suit.
+    pub fn parse(input: &str) -> Result<RuleSet, String> {
+        let mut rules = RuleSet::standard();
+        let mut hand_types: Option<&str> = None;
+        for (line_num, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+            let value = value.trim();
+            match key {
+                "cards" => rules.card_order = value.chars().collect(),
+                "wildcards" => rules.wildcards = value.chars().collect(),
+                "hand_size" => {
+                    rules.hand_size = value
+                        .parse()
+                        .ok()
+                        .filter(|n| (1..=MAX_HAND_SIZE).contains(n))
+                        .ok_or_else(|| {
+                            format!(
+                                "line {}: hand size must be 1 to {MAX_HAND_SIZE}",
+                                line_num + 1
+                            )
+                        })?
+                }
+                "flushes" => rules.suited = true,
+                "hand_types" => hand_types = Some(value),
+                _ => return Err(format!("line {}: unknown setting: {key}", line_num + 1)),
+            }
+        }
+        rules.hand_types = match hand_types {
+            Some(hand_types) => hand_types
+                .split_whitespace()
+                .map(|t| HandType::parse(t, rules.hand_size))
+                .collect::<Result<_, _>>()?,
+            None => RuleSet::default_hand_types(rules.hand_size, rules.suited),
+        };
+        rules.validate()?;
+        Ok(rules)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        // checked first, as there are a lot of hand types for a large hand
+        if !(1..=MAX_HAND_SIZE).contains(&self.hand_size) {
+            return Err(format!("hand size must be 1 to {MAX_HAND_SIZE}"));
+        }
+        if let Some(card) = self.card_order.iter().find(|c| self.wildcards.contains(c)) {
+            return Err(format!("{card} is both a wildcard and a regular card"));
+        }
+        for counts in partitions(self.hand_size, self.hand_size) {
+            let hand_type = HandType::from_counts(&counts);
+            if !self.hand_types.contains(&hand_type) {
+                return Err(format!("no rank for hand type {hand_type}"));
+            }
+        }
+        if self.suited && !self.hand_types.contains(&HandType::Flush) {
+            return Err("no rank for flushes".to_string());
+        }
+        if self.card_order.len() + self.wildcards.len() > 256 || self.hand_types.len() > 256 {
+            return Err("at most 256 cards and hand types".to_string());
+        }
+        Ok(())
+    }
+
+    pub fn card_value(&self, card: char) -> u32 {
+        let value = match self.wildcards.iter().position(|c| *c == card) {
+            Some(position) => position,
+            None => {
+                self.wildcards.len()
+                    + self
+                        .card_order
+                        .iter()
+                        .position(|c| *c == card)
+                        .unwrap_or_else(|| panic!("unknown card: {card}"))
+            }
+        };
+        value.try_into().unwrap()
+    }
+
+    // The best hand type the wildcards can make under this ranking, and the card each
+    // wildcard stands in for. Joining the most common card isn't always best when the hand
+    // types are reordered, so every way of playing the wildcards is tried; on a tie more
+    // wildcards go to the more common (then stronger) cards.
+    fn best_play(&self, hand: &Hand) -> (HandType, Vec<char>) {
+        let regular: Vec<char> = hand
+            .cards
+            .iter()
+            .copied()
+            .filter(|c| !self.wildcards.contains(c))
+            .collect();
+        let num_wildcards = hand.cards.len() - regular.len();
+
+        let mut groups: Vec<(char, usize)> = value_counts(&regular).into_iter().collect();
+        groups.sort_by_key(|(card, count)| (*count, self.card_value(*card)));
+        groups.reverse();
+        // new cards for the wildcards to form groups of their own, strongest first
+        let unused: Vec<char> = self
+            .card_order
+            .iter()
+            .rev()
+            .copied()
+            .filter(|c| !regular.contains(c))
+            .collect();
+
+        let mut best: Option<(usize, HandType, Vec<char>)> = None;
+        for (joined, new_groups) in wildcard_plays(groups.len(), num_wildcards, unused.len()) {
+            let mut counts: Vec<usize> = groups
+                .iter()
+                .zip(&joined)
+                .map(|((_, count), joined)| count + joined)
+                .chain(new_groups.iter().copied())
+                .collect();
+            counts.sort_unstable_by(|a, b| b.cmp(a));
+            let hand_type = HandType::from_counts(&counts);
+            let rank = self.hand_rank(&hand_type);
+            if best
+                .as_ref()
+                .is_some_and(|(best_rank, _, _)| *best_rank >= rank)
+            {
+                continue;
+            }
+
+            let substitutes = groups
+                .iter()
+                .zip(&joined)
+                .flat_map(|((card, _), joined)| std::iter::repeat_n(*card, *joined))
+                .chain(
+                    unused
+                        .iter()
+                        .zip(&new_groups)
+                        .flat_map(|(card, size)| std::iter::repeat_n(*card, *size)),
+                )
+                .collect();
+            best = Some((rank, hand_type, substitutes));
+        }
+        let (rank, hand_type, substitutes) = best.unwrap_or_else(|| {
+            // no regular cards to stand in for, so the wildcards are just cards
+            let mut counts: Vec<usize> = value_counts(&hand.cards).into_values().collect();
+            counts.sort_unstable_by(|a, b| b.cmp(a));
+            let hand_type = HandType::from_counts(&counts);
+            (self.hand_rank(&hand_type), hand_type, Vec::new())
+        });
+
+        // wildcards can be any suit too
+        let suits: Vec<char> = hand
+            .cards
+            .iter()
+            .zip(&hand.suits)
+            .filter(|(c, _)| !self.wildcards.contains(c))
+            .map(|(_, suit)| *suit)
+            .collect();
+        let is_flush = self.suited && suits.windows(2).all(|w| w[0] == w[1]);
+        if is_flush && self.hand_rank(&HandType::Flush) > rank {
+            (HandType::Flush, substitutes)
+        } else {
+            (hand_type, substitutes)
+        }
+    }
+
+    pub fn hand_type(&self, hand: &Hand) -> HandType {
+        self.best_play(hand).0
+    }
+
+    // what the wildcards stand in for, and the suit of the other cards if that makes a flush
+    pub fn best_substitution(&self, hand: &Hand) -> Hand {
+        let (hand_type, substitutes) = self.best_play(hand);
+        let flush_suit = match hand_type {
+            HandType::Flush => hand
+                .cards
+                .iter()
+                .zip(&hand.suits)
+                .find(|(c, _)| !self.wildcards.contains(c))
+                .map(|(_, suit)| *suit),
+            _ => None,
+        };
+
+        let mut result = hand.clone();
+        let mut substitutes = substitutes.into_iter();
+        for (i, card) in hand.cards.iter().enumerate() {
+            if !self.wildcards.contains(card) {
+                continue;
+            }
+            if let Some(substitute) = substitutes.next() {
+                result.cards[i] = substitute;
+            }
+            if let Some(suit) = flush_suit {
+                result.suits[i] = suit;
+            }
+        }
+        result
+    }
+
+    pub fn hand_rank(&self, hand_type: &HandType) -> usize {
+        self.hand_types
+            .iter()
+            .position(|t| t == hand_type)
+            .unwrap_or_else(|| panic!("no rank for hand type {hand_type}"))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hand {
+    pub cards: Vec<char>,
+    // empty unless the rules use suits
+    pub suits: Vec<char>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ProcessedHand {
+    pub hand_type: HandType,
+    pub hand_rank: usize,
+    pub card_values: Vec<u32>,
+}
+
+impl Hand {
+    pub fn parse(input: &str) -> Hand {
+        Hand {
+            cards: input.chars().collect(),
+            suits: Vec::new(),
+        }
+    }
+
+    pub fn parse_with(input: &str, rules: &RuleSet) -> Hand {
+        let hand = if rules.suited {
+            let chars: Vec<char> = input.chars().collect();
+            assert!(
+                chars.len().is_multiple_of(2),
+                "cards without a suit: {input}"
+            );
+            Hand {
+                cards: chars.iter().step_by(2).copied().collect(),
+                suits: chars.iter().skip(1).step_by(2).copied().collect(),
+            }
+        } else {
+            Hand::parse(input)
+        };
+        assert_eq!(
+            hand.cards.len(),
+            rules.hand_size,
+            "wrong number of cards: {input}"
+        );
+        hand
+    }
+
+    pub fn process(&self, rules: &RuleSet) -> ProcessedHand {
+        let card_values: Vec<u32> = self.cards.iter().map(|c| rules.card_value(*c)).collect();
+        let hand_type = rules.hand_type(self);
+
+        ProcessedHand {
+            hand_rank: rules.hand_rank(&hand_type),
+            hand_type,
+            card_values,
+        }
+    }
+}
+
+impl fmt::Display for Hand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, card) in self.cards.iter().enumerate() {
+            write!(f, "{card}")?;
+            if let Some(suit) = self.suits.get(i) {
+                write!(f, "{suit}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ProcessedHand {
+    // The hand rank and then every card value in a byte each, so comparing keys is the
+    // same as comparing hands. `RuleSet::validate` makes sure everything fits.
+    pub fn sort_key(&self) -> u128 {
+        let mut key = u128::try_from(self.hand_rank).unwrap() << 120;
+        for (i, value) in self.card_values.iter().enumerate() {
+            key |= u128::from(*value) << (112 - 8 * i);
+        }
+        key
+    }
+}
+
+impl Ord for ProcessedHand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.hand_rank
+            .cmp(&other.hand_rank)
+            .then_with(|| self.card_values.cmp(&other.card_values))
+    }
+}
+
+impl PartialOrd for ProcessedHand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+pub fn parse(input: &str) -> (Hand, u32) {
+    let (hand_str, bid_str) = input.split_once(' ').unwrap();
+    let hand = Hand::parse(hand_str);
+    let bid = bid_str.parse().unwrap();
+    (hand, bid)
+}
+
+pub fn parse_with(input: &str, rules: &RuleSet) -> (Hand, u32) {
+    let (hand_str, bid_str) = input.split_once(' ').unwrap();
+    let hand = Hand::parse_with(hand_str, rules);
+    let bid = bid_str.parse().unwrap();
+    (hand, bid)
+}
+
+pub struct Ranking {
+    // indices into the ranked hands, weakest first; equally strong hands keep their
+    // input order
+    pub order: Vec<usize>,
+    // runs of positions in `order` that hold equally strong hands
+    pub ties: Vec<Range<usize>>,
+}
+
+impl Ranking {
+    pub fn total_winnings(&self, hands_bids: &[(Hand, u32)]) -> u64 {
+        self.order
+            .iter()
+            .enumerate()
+            .map(|(i, hand)| (i as u64 + 1) * u64::from(hands_bids[*hand].1))
+            .sum()
+    }
+}
+
+fn rank_keys(mut keys: Vec<(u128, usize)>) -> Ranking {
+    keys.par_sort_unstable();
+
+    let mut ties = Vec::new();
+    let mut start = 0;
+    for run in keys.chunk_by(|a, b| a.0 == b.0) {
+        if run.len() > 1 {
+            ties.push(start..start + run.len());
+        }
+        start += run.len();
+    }
+
+    Ranking {
+        order: keys.into_iter().map(|(_, i)| i).collect(),
+        ties,
+    }
+}
+
+pub fn rank(hands_bids: &[(Hand, u32)], rules: &RuleSet) -> Ranking {
+    rank_keys(
+        hands_bids
+            .par_iter()
+            .enumerate()
+            .map(|(i, (hand, _))| (hand.process(rules).sort_key(), i))
+            .collect(),
+    )
+}
+
+pub fn total_winnings(hands_bids: &[(Hand, u32)], rules: &RuleSet) -> u64 {
+    rank(hands_bids, rules).total_winnings(hands_bids)
+}
+
+pub struct HandReport {
+    pub hand: Hand,
+    pub substitution: Hand,
+    pub hand_type: HandType,
+    pub rank: u32,
+    pub bid: u32,
+}
+
+impl HandReport {
+    pub fn winnings(&self) -> u64 {
+        u64::from(self.rank) * u64::from(self.bid)
+    }
+}
+
+impl fmt::Display for HandReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:>5}  {}  {}  {:<15}  {:>4} x {:>5} = {}",
+            self.rank,
+            self.hand,
+            self.substitution,
+            self.hand_type.to_string(),
+            self.bid,
+            self.rank,
+            self.winnings()
+        )
+    }
+}
+
+// every hand from weakest to strongest
+pub fn report(hands_bids: &[(Hand, u32)], rules: &RuleSet) -> Vec<HandReport> {
+    rank(hands_bids, rules)
+        .order
+        .into_iter()
+        .enumerate()
+        .map(|(i, index)| {
+            let (hand, bid) = &hands_bids[index];
+            HandReport {
+                hand: hand.clone(),
+                substitution: rules.best_substitution(hand),
+                hand_type: rules.hand_type(hand),
+                rank: i as u32 + 1,
+                bid: *bid,
+            }
+        })
+        .collect()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Parsed<'a> = Vec<(Hand, u32)>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Vec<(Hand, u32)> {
+        input.lines().map(parse).collect()
+    }
+
+    fn part1(hands_bids: &Vec<(Hand, u32)>) -> u64 {
+        total_winnings(hands_bids, &RuleSet::standard())
+    }
+
+    fn part2(hands_bids: &Vec<(Hand, u32)>) -> u64 {
+        total_winnings(hands_bids, &RuleSet::jokers())
+    }
+}
+
+fn total_winnings_streaming<R: BufRead>(reader: R, rules: &RuleSet) -> io::Result<u64> {
+    // ranking needs every hand, but only the sort key and the bid are kept
+    let mut keys: Vec<(u128, usize)> = Vec::new();
+    let mut bids: Vec<u32> = Vec::new();
+    for line in stream::lines(reader) {
+        let (hand, bid) = parse_with(&line?, rules);
+        keys.push((hand.process(rules).sort_key(), bids.len()));
+        bids.push(bid);
+    }
+
+    Ok(rank_keys(keys)
+        .order
+        .iter()
+        .enumerate()
+        .map(|(i, hand)| (i as u64 + 1) * u64::from(bids[*hand]))
+        .sum())
+}
+
+impl StreamingSolution for Day07 {
+    fn part1_streaming<R: BufRead>(reader: R) -> io::Result<u64> {
+        total_winnings_streaming(reader, &RuleSet::standard())
+    }
+
+    fn part2_streaming<R: BufRead>(reader: R) -> io::Result<u64> {
+        total_winnings_streaming(reader, &RuleSet::jokers())
+    }
+}
+
+pub const EXAMPLE: &str = "\
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example1() {
+        assert_eq!(Day07::solve1(EXAMPLE), 6440);
+    }
+
+    #[test]
+    fn example2() {
+        assert_eq!(Day07::solve2(EXAMPLE), 5905);
+    }
+
+    #[test]
+    fn streaming() {
+        assert_eq!(Day07::part1_streaming(EXAMPLE.as_bytes()).unwrap(), 6440);
+        assert_eq!(Day07::part2_streaming(EXAMPLE.as_bytes()).unwrap(), 5905);
+    }
+
+    #[test]
+    fn rule_sets() {
+        let standard = RuleSet::standard();
+        assert_eq!(standard.hand_types.len(), 7);
+        let hand_type = |rules: &RuleSet, hand| rules.hand_type(&Hand::parse_with(hand, rules));
+        assert_eq!(hand_type(&standard, "KTJJT"), HandType::TwoPair);
+        assert_eq!(
+            hand_type(&RuleSet::jokers(), "KTJJT"),
+            HandType::FourOfAKind
+        );
+
+        let rules = RuleSet::parse(
+            "\
+# jokers and deuces are wild, jokers are worth less
+cards 3456789TQKA
+wildcards J2
+hand_size 6
+flushes",
+        )
+        .unwrap();
+        assert_eq!(rules.hand_types.len(), 12);
+        assert!(rules.card_value('J') < rules.card_value('2'));
+        assert!(rules.card_value('2') < rules.card_value('3'));
+        assert_eq!(hand_type(&rules, "JsAh2c3d3h9s"), HandType::FourOfAKind);
+        assert_eq!(hand_type(&rules, "AhKh5hJs6h9h"), HandType::Flush);
+        assert_eq!(hand_type(&rules, "AhAd5hJs6h9h"), HandType::ThreeOfAKind);
+        assert_eq!(hand_type(&rules, "AhAh5hJs6h9h"), HandType::Flush);
+        assert_eq!(hand_type(&rules, "AhAdAcJs6h9h"), HandType::FourOfAKind);
+
+        assert!(RuleSet::parse("wildcards J").is_err());
+        assert!(RuleSet::parse("hand_size 0").is_err());
+        assert!(RuleSet::parse("hand_size 40").is_err());
+        assert!(RuleSet::parse("hand_size 1000000").is_err());
+        assert!(RuleSet::parse("hand_types 11111 2111 221 311 32 41").is_err());
+    }
+
+    #[test]
+    fn all_wildcards() {
+        let rules = RuleSet::jokers();
+        let hand = Hand::parse_with("JJJJJ", &rules);
+        assert_eq!(rules.hand_type(&hand), HandType::FiveOfAKind);
+        assert_eq!(rules.best_substitution(&hand).to_string(), "AAAAA");
+
+        // still the weakest five of a kind, but above every four of a kind
+        let input = "JJJJJ 1\nAAAAK 2\n22222 3";
+        assert_eq!(rank(&Day07::parse(input), &rules).order, vec![1, 0, 2]);
+        assert_eq!(Day07::solve2(input), 2 + 2 + 3 * 3);
+    }
+
+    #[test]
+    fn reordered_hand_types() {
+        // two pair beats three of a kind here, so the joker should make the second pair
+        let rules = RuleSet::parse(
+            "\
+cards 23456789TQKA
+wildcards J
+hand_types 11111 2111 311 221 32 41 5",
+        )
+        .unwrap();
+        let hand = Hand::parse_with("AAKQJ", &rules);
+        assert_eq!(rules.hand_type(&hand), HandType::TwoPair);
+        assert_eq!(rules.best_substitution(&hand).to_string(), "AAKQK");
+
+        let hands_bids = Day07::parse("AAKQJ 1\n22334 2");
+        assert_eq!(rank(&hands_bids, &rules).order, vec![1, 0]);
+
+        let hand = Hand::parse_with("AKQJJ", &rules);
+        assert_eq!(rules.hand_type(&hand), HandType::TwoPair);
+        assert_eq!(rules.best_substitution(&hand).to_string(), "AKQAK");
+    }
+
+    #[test]
+    fn hand_report() {
+        let hands_bids = Day07::parse(EXAMPLE);
+        let lines = report(&hands_bids, &RuleSet::jokers());
+        assert_eq!(lines.iter().map(|l| l.winnings()).sum::<u64>(), 5905);
+        assert_eq!(
+            lines.iter().map(|l| l.to_string()).collect::<Vec<_>>(),
+            vec![
+                "    1  32T3K  32T3K  One pair          765 x     1 = 765",
+                "    2  KK677  KK677  Two pair           28 x     2 = 56",
+                "    3  T55J5  T5555  Four of a kind    684 x     3 = 2052",
+                "    4  QQQJA  QQQQA  Four of a kind    483 x     4 = 1932",
+                "    5  KTJJT  KTTTT  Four of a kind    220 x     5 = 1100",
+            ]
+        );
+
+        assert_eq!(HandType::from_counts(&[3, 3]).to_string(), "3+3");
+        assert_eq!(HandType::from_counts(&[6]).to_string(), "6 of a kind");
+    }
+
+    #[test]
+    fn ranking() {
+        let rules = RuleSet::jokers();
+        let hands_bids = Day07::parse(EXAMPLE);
+        let processed: Vec<ProcessedHand> =
+            hands_bids.iter().map(|(h, _)| h.process(&rules)).collect();
+        for a in &processed {
+            for b in &processed {
+                assert_eq!(a.cmp(b), a.sort_key().cmp(&b.sort_key()));
+            }
+        }
+
+        let ranking = rank(&hands_bids, &rules);
+        assert_eq!(ranking.order, vec![0, 2, 1, 4, 3]);
+        assert!(ranking.ties.is_empty());
+
+        let with_ties = Day07::parse("KK677 1\n32T3K 2\nKK677 3\nKK677 4\n32T3K 5");
+        let ranking = rank(&with_ties, &rules);
+        assert_eq!(ranking.order, vec![1, 4, 0, 2, 3]);
+        assert_eq!(ranking.ties, vec![0..2, 2..5]);
+    }
+}
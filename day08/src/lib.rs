@@ -0,0 +1,697 @@
+use aoc::Solution;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+// An index into the network's node names, which can be any length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NodeId(pub usize);
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum NetworkError {
+    UnknownNode(String),
+    UndefinedNode(String),
+    DuplicateNode(String),
+    NoInstructions,
+    Unreachable { start: String, num_steps: u64 },
+    Overflow,
+}
+
+impl fmt::Display for NetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::UnknownNode(name) => write!(f, "unknown node: {name}"),
+            Self::UndefinedNode(name) => write!(f, "node {name} is a child but is never defined"),
+            Self::DuplicateNode(name) => write!(f, "node {name} is defined more than once"),
+            Self::NoInstructions => write!(f, "there are no left/right instructions"),
+            Self::Unreachable { start, num_steps } => write!(
+                f,
+                "no target node is reachable from {start}, the path repeats after {num_steps} steps"
+            ),
+            Self::Overflow => write!(f, "the number of steps doesn't fit in 128 bits"),
+        }
+    }
+}
+
+impl std::error::Error for NetworkError {}
+
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub fn parse(input: char) -> Direction {
+        match input {
+            'L' => Direction::Left,
+            'R' => Direction::Right,
+            _ => panic!(),
+        }
+    }
+
+    pub fn value(&self) -> usize {
+        match self {
+            Self::Left => 0,
+            Self::Right => 1,
+        }
+    }
+}
+
+pub struct Node {
+    pub id: NodeId,
+    pub children: [NodeId; 2],
+}
+
+pub struct Network {
+    pub nodes: HashMap<NodeId, Node>,
+    names: Vec<String>,
+    ids: HashMap<String, NodeId>,
+    // nodes with more than one line, in the order they were redefined
+    redefined: Vec<NodeId>,
+}
+
+impl Network {
+    pub fn parse(input: &str) -> Network {
+        let mut network = Network {
+            nodes: HashMap::new(),
+            names: Vec::new(),
+            ids: HashMap::new(),
+            redefined: Vec::new(),
+        };
+        for line in input.lines() {
+            let (id_part, lr_part) = line.split_once(" = ").unwrap();
+
+            let id = network.intern(id_part.trim());
+            let (l_part, r_part) = lr_part
+                .strip_prefix('(')
+                .and_then(|s| s.strip_suffix(')'))
+                .and_then(|s| s.split_once(", "))
+                .unwrap();
+            let left = network.intern(l_part.trim());
+            let right = network.intern(r_part.trim());
+
+            let node = Node {
+                id,
+                children: [left, right],
+            };
+            if network.nodes.insert(id, node).is_some() {
+                network.redefined.push(id);
+            }
+        }
+
+        network
+    }
+
+    fn intern(&mut self, name: &str) -> NodeId {
+        if let Some(id) = self.ids.get(name) {
+            return *id;
+        }
+        let id = NodeId(self.names.len());
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        id
+    }
+
+    pub fn id(&self, name: &str) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: NodeId) -> &str {
+        &self.names[id.0]
+    }
+
+    pub fn children(&self, id: NodeId) -> Result<[NodeId; 2], NetworkError> {
+        self.nodes
+            .get(&id)
+            .map(|node| node.children)
+            .ok_or_else(|| NetworkError::UndefinedNode(self.name(id).to_string()))
+    }
+
+    // every node has exactly one line, including the ones that are only ever children
+    pub fn validate(&self) -> Result<(), NetworkError> {
+        if let Some(id) = self.redefined.first() {
+            return Err(NetworkError::DuplicateNode(self.name(*id).to_string()));
+        }
+        (0..self.names.len()).try_for_each(|i| self.children(NodeId(i)).map(|_| ()))
+    }
+}
+
+pub struct PuzzleInput {
+    pub instructions: Vec<Direction>,
+    pub network: Network,
+}
+
+impl PuzzleInput {
+    pub fn parse(input: &str) -> PuzzleInput {
+        let (instructions_part, network_part) = input.split_once("\n\n").unwrap();
+        let instructions = instructions_part.chars().map(Direction::parse).collect();
+        let network = Network::parse(network_part);
+
+        PuzzleInput {
+            instructions,
+            network,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), NetworkError> {
+        if self.instructions.is_empty() {
+            return Err(NetworkError::NoInstructions);
+        }
+        self.network.validate()
+    }
+
+    pub fn steps_between(&self, start: &str, target: &str) -> Result<u64, NetworkError> {
+        self.validate()?;
+        let start_id = self
+            .network
+            .id(start)
+            .ok_or_else(|| NetworkError::UnknownNode(start.to_string()))?;
+        if self.network.id(target).is_none() {
+            return Err(NetworkError::UnknownNode(target.to_string()));
+        }
+        num_steps_to_node(start_id, &self.network, &self.instructions, |name| {
+            name == target
+        })
+    }
+}
+
+// Gives up once the walk is back in a (node, instruction index) state it has already been
+// in without passing a target node.
+pub fn num_steps_to_node(
+    start_node_id: NodeId,
+    network: &Network,
+    instructions: &[Direction],
+    node_match_fn: impl Fn(&str) -> bool,
+) -> Result<u64, NetworkError> {
+    if instructions.is_empty() {
+        return Err(NetworkError::NoInstructions);
+    }
+    let mut visited = HashSet::new();
+    let mut current_node_id = start_node_id;
+    let mut num_steps = 0;
+
+    while !node_match_fn(network.name(current_node_id)) {
+        let instruction = (num_steps % instructions.len() as u64) as usize;
+        if !visited.insert((current_node_id, instruction)) {
+            return Err(NetworkError::Unreachable {
+                start: network.name(start_node_id).to_string(),
+                num_steps,
+            });
+        }
+
+        current_node_id = network.children(current_node_id)?[instructions[instruction].value()];
+        num_steps += 1;
+    }
+
+    Ok(num_steps)
+}
+
+// Where a ghost is a target node: `hits` lists every step before the path first returns to
+// a (node, instruction index) state it has been in, and from `cycle_start` on the path
+// repeats every `cycle_len` steps.
+#[derive(Debug, PartialEq, Eq)]
+pub struct GhostPath {
+    pub cycle_start: u64,
+    pub cycle_len: u64,
+    pub hits: Vec<u64>,
+}
+
+impl GhostPath {
+    pub fn walk(
+        start_node_id: NodeId,
+        network: &Network,
+        instructions: &[Direction],
+        node_match_fn: impl Fn(&str) -> bool,
+    ) -> Result<GhostPath, NetworkError> {
+        if instructions.is_empty() {
+            return Err(NetworkError::NoInstructions);
+        }
+        let mut visited: HashMap<(NodeId, usize), u64> = HashMap::new();
+        let mut hits = Vec::new();
+        let mut current_node_id = start_node_id;
+        let mut num_steps = 0;
+
+        loop {
+            let instruction = (num_steps % instructions.len() as u64) as usize;
+            if let Some(cycle_start) = visited.insert((current_node_id, instruction), num_steps) {
+                return Ok(GhostPath {
+                    cycle_start,
+                    cycle_len: num_steps - cycle_start,
+                    hits,
+                });
+            }
+            if node_match_fn(network.name(current_node_id)) {
+                hits.push(num_steps);
+            }
+
+            current_node_id = network.children(current_node_id)?[instructions[instruction].value()];
+            num_steps += 1;
+        }
+    }
+
+    pub fn is_hit(&self, num_steps: u64) -> bool {
+        if num_steps < self.cycle_start {
+            return self.hits.contains(&num_steps);
+        }
+        let offset = (num_steps - self.cycle_start) % self.cycle_len;
+        self.hits.contains(&(self.cycle_start + offset))
+    }
+}
+
+// x = a mod m and x = b mod n, for moduli that don't have to be coprime. None if there is no
+// such x, and an error if the combined modulus doesn't fit in a u128.
+fn combine(
+    (a, m): (u128, u128),
+    (b, n): (u128, u128),
+) -> Result<Option<(u128, u128)>, NetworkError> {
+    let g = gcd(m, n);
+    let diff = sub_mod(b % n, a % n, n);
+    if !diff.is_multiple_of(g) {
+        return Ok(None);
+    }
+    let (m_g, n_g) = (m / g, n / g);
+    // m * k = diff (mod n)  =>  k = (diff / g) * (m / g)^-1 (mod n / g)
+    let k = mul_mod((diff / g) % n_g, mod_inverse(m_g % n_g, n_g), n_g);
+    let modulus = m_g.checked_mul(n).ok_or(NetworkError::Overflow)?;
+    // a < m and k < n / g, so this stays below the modulus
+    Ok(Some((a + m * k, modulus)))
+}
+
+fn gcd(a: u128, b: u128) -> u128 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+// for a and b below the modulus
+fn add_mod(a: u128, b: u128, modulus: u128) -> u128 {
+    if a >= modulus - b {
+        a - (modulus - b)
+    } else {
+        a + b
+    }
+}
+
+// for a and b below the modulus
+fn sub_mod(a: u128, b: u128, modulus: u128) -> u128 {
+    if a >= b {
+        a - b
+    } else {
+        modulus - (b - a)
+    }
+}
+
+// for a and b below the modulus; doubles and adds when a * b doesn't fit
+fn mul_mod(a: u128, b: u128, modulus: u128) -> u128 {
+    if let Some(product) = a.checked_mul(b) {
+        return product % modulus;
+    }
+    let (mut a, mut b, mut product) = (a, b, 0);
+    while b > 0 {
+        if b & 1 == 1 {
+            product = add_mod(product, a, modulus);
+        }
+        a = add_mod(a, a, modulus);
+        b >>= 1;
+    }
+    product
+}
+
+// a^-1 mod the modulus, for a coprime to it. The coefficients of the extended Euclidean
+// algorithm are kept modulo the modulus so that none of it needs signed arithmetic.
+fn mod_inverse(a: u128, modulus: u128) -> u128 {
+    if modulus == 1 {
+        return 0;
+    }
+    let (mut old_r, mut r) = (a, modulus);
+    let (mut old_s, mut s) = (1, 0);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, sub_mod(old_s, mul_mod(q % modulus, s, modulus), modulus));
+    }
+    old_s
+}
+
+// The first step at which every ghost is on a target node, or None if that never happens.
+// Each ghost's hits in its cycle are combined with every solution so far, so the work and
+// the number of solutions kept grow with the product of the ghosts' hits per cycle.
+// Errors if the step count doesn't fit in a u128.
+pub fn first_sync(ghosts: &[GhostPath]) -> Result<Option<u128>, NetworkError> {
+    let Some(periodic_from) = ghosts.iter().map(|g| g.cycle_start).max() else {
+        return Ok(None);
+    };
+
+    // before then the slowest ghost is still on its way into its cycle
+    let slowest = ghosts
+        .iter()
+        .find(|g| g.cycle_start == periodic_from)
+        .expect("the latest cycle start belongs to some ghost");
+    if let Some(num_steps) = slowest
+        .hits
+        .iter()
+        .copied()
+        .filter(|t| *t < periodic_from)
+        .find(|t| ghosts.iter().all(|g| g.is_hit(*t)))
+    {
+        return Ok(Some(u128::from(num_steps)));
+    }
+
+    // after that each ghost is a set of residues modulo its cycle length
+    let mut solutions: Vec<(u128, u128)> = vec![(0, 1)];
+    for ghost in ghosts {
+        let modulus = u128::from(ghost.cycle_len);
+        let residues: Vec<(u128, u128)> = ghost
+            .hits
+            .iter()
+            .filter(|t| **t >= ghost.cycle_start)
+            .map(|t| (u128::from(*t) % modulus, modulus))
+            .collect();
+        let mut combined = Vec::new();
+        for s in &solutions {
+            for r in &residues {
+                combined.extend(combine(*s, *r)?);
+            }
+        }
+        solutions = combined;
+        solutions.sort_unstable();
+        solutions.dedup();
+    }
+
+    let periodic_from = u128::from(periodic_from);
+    solutions
+        .into_iter()
+        .map(|(residue, modulus)| {
+            if residue >= periodic_from {
+                Ok(residue)
+            } else {
+                (periodic_from - residue)
+                    .div_ceil(modulus)
+                    .checked_mul(modulus)
+                    .and_then(|offset| offset.checked_add(residue))
+                    .ok_or(NetworkError::Overflow)
+            }
+        })
+        .try_fold(None, |first: Option<u128>, num_steps| {
+            let num_steps = num_steps?;
+            Ok(Some(first.map_or(num_steps, |first| first.min(num_steps))))
+        })
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Parsed<'a> = PuzzleInput;
+    type Output1 = u64;
+    type Output2 = u128;
+
+    fn parse(input: &str) -> PuzzleInput {
+        PuzzleInput::parse(input)
+    }
+
+    fn part1(puzzle: &PuzzleInput) -> u64 {
+        puzzle
+            .steps_between("AAA", "ZZZ")
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    fn part2(puzzle: &PuzzleInput) -> u128 {
+        let network = &puzzle.network;
+        puzzle.validate().unwrap_or_else(|e| panic!("{e}"));
+        let ghosts: Vec<GhostPath> = network
+            .nodes
+            .keys()
+            .filter(|id| network.name(**id).ends_with('A'))
+            .map(|id| {
+                GhostPath::walk(*id, network, &puzzle.instructions, |name| {
+                    name.ends_with('Z')
+                })
+            })
+            .collect::<Result<_, _>>()
+            .unwrap_or_else(|e| panic!("{e}"));
+
+        first_sync(&ghosts)
+            .unwrap_or_else(|e| panic!("{e}"))
+            .expect("the ghosts are never all on a Z node at once")
+    }
+}
+
+pub const EXAMPLE: &str = "\
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example1() {
+        assert_eq!(Day08::solve1(EXAMPLE), 2);
+    }
+
+    #[test]
+    fn example1b() {
+        let input = "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+        assert_eq!(Day08::solve1(input), 6);
+    }
+
+    #[test]
+    fn example2() {
+        let input = "\
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+        assert_eq!(Day08::solve2(input), 6);
+    }
+
+    fn simulate(puzzle: &PuzzleInput, limit: u64) -> Option<u64> {
+        let network = &puzzle.network;
+        let mut current: Vec<NodeId> = network
+            .nodes
+            .keys()
+            .filter(|id| network.name(**id).ends_with('A'))
+            .copied()
+            .collect();
+        for num_steps in 0..limit {
+            if current.iter().all(|id| network.name(*id).ends_with('Z')) {
+                return Some(num_steps);
+            }
+            let direction = &puzzle.instructions[num_steps as usize % puzzle.instructions.len()];
+            for node_id in current.iter_mut() {
+                *node_id = network.children(*node_id).unwrap()[direction.value()];
+            }
+        }
+        None
+    }
+
+    #[test]
+    fn ghosts_out_of_phase() {
+        // the first ghost reaches 11Z after 2 steps but then every 3 steps
+        let input = "\
+L
+
+11A = (11B, 11B)
+11B = (11Z, 11Z)
+11Z = (11C, 11C)
+11C = (11D, 11D)
+11D = (11Z, 11Z)
+22A = (22Z, 22Z)
+22Z = (22B, 22B)
+22B = (22Z, 22Z)";
+        let puzzle = PuzzleInput::parse(input);
+        assert_eq!(simulate(&puzzle, 100), Some(5));
+        assert_eq!(Day08::part2(&puzzle), 5);
+
+        // a third ghost that is only ever on 33Z after an even number of steps
+        let input = format!("{input}\n33A = (33B, 33B)\n33B = (33Z, 33Z)\n33Z = (33B, 33B)");
+        let puzzle = PuzzleInput::parse(&input);
+        let walk = |id| {
+            GhostPath::walk(
+                puzzle.network.id(id).unwrap(),
+                &puzzle.network,
+                &puzzle.instructions,
+                |name| name.ends_with('Z'),
+            )
+            .unwrap()
+        };
+        assert_eq!(
+            walk("11A"),
+            GhostPath {
+                cycle_start: 2,
+                cycle_len: 3,
+                hits: vec![2]
+            }
+        );
+        assert_eq!(first_sync(&[walk("11A"), walk("33A")]), Ok(Some(2)));
+        assert_eq!(first_sync(&[walk("22A"), walk("33A")]), Ok(None));
+        assert_eq!(simulate(&puzzle, 1000), None);
+    }
+
+    #[test]
+    fn several_hits_per_cycle() {
+        // each ghost walks a loop of `len` nodes, of which the ones at `hits` end in Z
+        let mut input = String::from("L\n");
+        for (ghost, len, hits) in [
+            ('a', 4, &[1, 2][..]),
+            ('b', 6, &[0, 3, 4]),
+            ('c', 9, &[2, 7]),
+        ] {
+            let name = |i| format!("{ghost}{i}{}", if hits.contains(&i) { 'Z' } else { 'X' });
+            input += &format!("\n{ghost}A = ({}, {})", name(0), name(0));
+            for i in 0..len {
+                let next = name((i + 1) % len);
+                input += &format!("\n{} = ({next}, {next})", name(i));
+            }
+        }
+        let puzzle = PuzzleInput::parse(&input);
+        assert_eq!(simulate(&puzzle, 1000), Some(35));
+        assert_eq!(Day08::part2(&puzzle), 35);
+    }
+
+    #[test]
+    fn long_cycles() {
+        // primes just under 2^64, 2^63 and 2^61
+        let lens: [u64; 3] = [
+            18_446_744_073_709_551_557,
+            9_223_372_036_854_775_783,
+            2_305_843_009_213_693_951,
+        ];
+        let ghost = |cycle_len, hit| GhostPath {
+            cycle_start: 0,
+            cycle_len,
+            hits: vec![hit],
+        };
+        let (a, b) = (lens[0] - 1, 12_345);
+        let num_steps = first_sync(&[ghost(lens[0], a), ghost(lens[1], b)])
+            .unwrap()
+            .unwrap();
+        assert_eq!(num_steps % u128::from(lens[0]), u128::from(a));
+        assert_eq!(num_steps % u128::from(lens[1]), u128::from(b));
+        assert!(num_steps < u128::from(lens[0]) * u128::from(lens[1]));
+
+        // sharing a cycle length with an earlier ghost keeps the modulus in range
+        let ghosts = [ghost(lens[0], a), ghost(lens[1], b), ghost(lens[0], a)];
+        assert_eq!(first_sync(&ghosts), Ok(Some(num_steps)));
+        let ghosts = [ghost(lens[0], a), ghost(lens[1], b), ghost(lens[0], a - 1)];
+        assert_eq!(first_sync(&ghosts), Ok(None));
+
+        let ghosts = [ghost(lens[0], a), ghost(lens[1], b), ghost(lens[2], 1)];
+        assert_eq!(first_sync(&ghosts), Err(NetworkError::Overflow));
+    }
+
+    #[test]
+    fn long_node_ids() {
+        let input = "\
+LR
+
+START = (MIDDLE, X)
+MIDDLE = (X, GOAL)
+GOAL = (GOAL, GOAL)
+X = (X, X)
+1A = (2Z, 2Z)
+2Z = (1A, 1A)
+ALPHA = (OMEGAZ, OMEGAZ)
+OMEGAZ = (ALPHA, ALPHA)";
+        let puzzle = PuzzleInput::parse(input);
+        assert_eq!(
+            puzzle.network.name(puzzle.network.id("MIDDLE").unwrap()),
+            "MIDDLE"
+        );
+        assert_eq!(puzzle.steps_between("START", "GOAL"), Ok(2));
+        assert_eq!(Day08::part2(&puzzle), 1);
+    }
+
+    #[test]
+    fn unreachable_targets() {
+        let input = "\
+LR
+
+AAA = (BBB, AAA)
+BBB = (AAA, BBB)
+ZZZ = (ZZZ, ZZZ)
+YYY = (QQQ, ZZZ)";
+        let puzzle = PuzzleInput::parse(input);
+        // QQQ is off the path, but it still makes the network invalid
+        assert_eq!(
+            puzzle.network.validate(),
+            Err(NetworkError::UndefinedNode("QQQ".to_string()))
+        );
+        assert_eq!(
+            puzzle.steps_between("AAA", "BBB"),
+            Err(NetworkError::UndefinedNode("QQQ".to_string()))
+        );
+
+        let puzzle = PuzzleInput::parse(&input.replace("QQQ", "ZZZ"));
+        assert_eq!(puzzle.network.validate(), Ok(()));
+        let error = puzzle.steps_between("AAA", "ZZZ").unwrap_err();
+        assert_eq!(
+            error,
+            NetworkError::Unreachable {
+                start: "AAA".to_string(),
+                num_steps: 4
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "no target node is reachable from AAA, the path repeats after 4 steps"
+        );
+        assert_eq!(
+            puzzle.steps_between("AAA", "ABC"),
+            Err(NetworkError::UnknownNode("ABC".to_string()))
+        );
+    }
+
+    #[test]
+    fn malformed_networks() {
+        let puzzle = PuzzleInput::parse("\n\nAAA = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)");
+        assert_eq!(puzzle.validate(), Err(NetworkError::NoInstructions));
+        assert_eq!(
+            puzzle.steps_between("AAA", "ZZZ"),
+            Err(NetworkError::NoInstructions)
+        );
+        let start = puzzle.network.id("AAA").unwrap();
+        assert_eq!(
+            num_steps_to_node(start, &puzzle.network, &[], |name| name == "ZZZ"),
+            Err(NetworkError::NoInstructions)
+        );
+        assert_eq!(
+            GhostPath::walk(start, &puzzle.network, &[], |name| name.ends_with('Z')),
+            Err(NetworkError::NoInstructions)
+        );
+
+        let input = "\
+L
+
+AAA = (BBB, BBB)
+BBB = (ZZZ, ZZZ)
+AAA = (ZZZ, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+        let puzzle = PuzzleInput::parse(input);
+        let error = puzzle.network.validate().unwrap_err();
+        assert_eq!(error, NetworkError::DuplicateNode("AAA".to_string()));
+        assert_eq!(error.to_string(), "node AAA is defined more than once");
+        assert_eq!(puzzle.steps_between("AAA", "ZZZ"), Err(error));
+    }
+}
@@ -1,216 +1,28 @@
-use std::collections::HashMap;
+use day08::PuzzleInput;
+use std::{env, fs, process};
 
-type NodeId = [char; 3];
-
-fn parse_node_id(input: &str) -> NodeId {
-    let mut chars = input.chars();
-    [
-        chars.next().unwrap(),
-        chars.next().unwrap(),
-        chars.next().unwrap(),
-    ]
-}
-
-enum Direction {
-    Left,
-    Right,
-}
-
-impl Direction {
-    fn parse(input: char) -> Direction {
-        match input {
-            'L' => Direction::Left,
-            'R' => Direction::Right,
-            _ => panic!(),
-        }
-    }
-
-    fn value(&self) -> usize {
-        match self {
-            Self::Left => 0,
-            Self::Right => 1,
-        }
-    }
-}
-
-struct Node {
-    #[allow(dead_code)]
-    id: NodeId,
-    children: [NodeId; 2],
-}
-
-struct Network {
-    nodes: HashMap<NodeId, Node>,
-}
-
-impl Network {
-    fn parse(input: &str) -> Network {
-        let mut nodes = HashMap::new();
-        for line in input.lines() {
-            let (id_part, lr_part) = line.split_once(" = ").unwrap();
-
-            let id = parse_node_id(id_part);
-            let (l_part, r_part) = lr_part
-                .strip_prefix('(')
-                .and_then(|s| s.strip_suffix(')'))
-                .and_then(|s| s.split_once(", "))
-                .unwrap();
-            let left = parse_node_id(l_part);
-            let right = parse_node_id(r_part);
-
-            nodes.insert(
-                id,
-                Node {
-                    id,
-                    children: [left, right],
-                },
-            );
-        }
-
-        Network { nodes }
-    }
-}
-
-struct PuzzleInput {
-    instructions: Vec<Direction>,
-    network: Network,
-}
-
-impl PuzzleInput {
-    fn parse(input: &str) -> PuzzleInput {
-        let (instructions_part, network_part) = input.split_once("\n\n").unwrap();
-        let instructions = instructions_part.chars().map(Direction::parse).collect();
-        let network = Network::parse(network_part);
-
-        PuzzleInput {
-            instructions,
-            network,
-        }
-    }
-}
-
-fn num_steps_to_node(
-    start_node_id: &NodeId,
-    network: &Network,
-    instructions: &[Direction],
-    node_match_fn: impl Fn(&NodeId) -> bool,
-) -> u64 {
-    let mut current_node_id = start_node_id;
-    let mut num_steps = 0;
-
-    let mut instructions = instructions.iter().cycle();
-
-    while !node_match_fn(current_node_id) {
-        let direction = instructions.next().unwrap();
-        let current_node = network.nodes.get(current_node_id).unwrap();
-
-        current_node_id = current_node.children.get(direction.value()).unwrap();
-        num_steps += 1;
-    }
-
-    num_steps
-}
-
-fn solve1(input: &str) -> u64 {
-    let puzzle = PuzzleInput::parse(input);
-
-    num_steps_to_node(
-        &['A', 'A', 'A'],
-        &puzzle.network,
-        &puzzle.instructions,
-        |node_id| node_id == &['Z', 'Z', 'Z'],
-    )
-}
-
-// thanks for the help ChatGPT!
-fn gcd(a: u64, b: u64) -> u64 {
-    if b == 0 {
-        a
-    } else {
-        gcd(b, a % b)
-    }
-}
-
-fn lcm(a: u64, b: u64) -> u64 {
-    if a == 0 || b == 0 {
-        0
-    } else {
-        (a * b) / gcd(a, b)
-    }
-}
-
-fn solve2(input: &str) -> u64 {
-    let puzzle = PuzzleInput::parse(input);
-
-    puzzle
-        .network
-        .nodes
-        .keys()
-        .filter(|k| k.get(2).unwrap() == &'A')
-        .map(|a_node| {
-            num_steps_to_node(a_node, &puzzle.network, &puzzle.instructions, |node_id| {
-                node_id.get(2).unwrap() == &'Z'
-            })
-        })
-        .fold(1, lcm)
-}
+const USAGE: &str = "usage: day08 [steps <start> <target> [<network>]]";
 
 fn main() {
-    use aoc::Timer;
     let input = include_str!("input.txt");
 
-    let timer = Timer::new();
-    let result1 = solve1(input);
-    println!("Part 1: {} ({}ms)", result1, timer.elapsed().as_millis());
-
-    let timer = Timer::new();
-    let result2 = solve2(input);
-    println!("Part 2: {} ({}ms)", result2, timer.elapsed().as_millis());
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn example1() {
-        let input = "\
-RL
-
-AAA = (BBB, CCC)
-BBB = (DDD, EEE)
-CCC = (ZZZ, GGG)
-DDD = (DDD, DDD)
-EEE = (EEE, EEE)
-GGG = (GGG, GGG)
-ZZZ = (ZZZ, ZZZ)";
-        assert_eq!(solve1(input), 2);
-    }
-
-    #[test]
-    fn example1b() {
-        let input = "\
-LLR
-
-AAA = (BBB, BBB)
-BBB = (AAA, ZZZ)
-ZZZ = (ZZZ, ZZZ)";
-        assert_eq!(solve1(input), 6);
-    }
-
-    #[test]
-    fn example2() {
-        let input = "\
-LR
-
-11A = (11B, XXX)
-11B = (XXX, 11Z)
-11Z = (11B, XXX)
-22A = (22B, XXX)
-22B = (22C, 22C)
-22C = (22Z, 22Z)
-22Z = (22B, 22B)
-XXX = (XXX, XXX)";
-        assert_eq!(solve2(input), 6);
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        None => aoc::run::<day08::Day08>(input),
+        Some("steps") if args.len() == 3 || args.len() == 4 => {
+            let network = match args.get(3) {
+                Some(path) => fs::read_to_string(path).unwrap_or_else(|e| panic!("{path}: {e}")),
+                None => input.to_string(),
+            };
+            let puzzle = PuzzleInput::parse(&network);
+            match puzzle.steps_between(&args[1], &args[2]) {
+                Ok(num_steps) => println!("{} -> {}: {num_steps} steps", args[1], args[2]),
+                Err(e) => {
+                    println!("{e}");
+                    process::exit(1);
+                }
+            }
+        }
+        Some(_) => panic!("{USAGE}"),
     }
 }
@@ -0,0 +1,102 @@
+use aoc::stream::{self, StreamingSolution};
+use aoc::Solution;
+use std::io::{self, BufRead};
+
+pub fn parse_number_list(input: &str) -> Vec<i32> {
+    input
+        .split_whitespace()
+        .map(|num_str| num_str.parse().unwrap())
+        .collect()
+}
+
+pub fn parse_input(input: &str) -> Vec<Vec<i32>> {
+    input.lines().map(parse_number_list).collect()
+}
+
+pub fn differences(history: &[i32]) -> Vec<i32> {
+    history
+        .windows(2)
+        .map(|nums| nums.last().unwrap() - nums.first().unwrap())
+        .collect()
+}
+
+pub fn extrapolate(history: &[i32]) -> i32 {
+    if history.iter().all(|d| d == &0) {
+        0
+    } else {
+        let diffs = differences(history);
+        history.last().unwrap() + extrapolate(&diffs)
+    }
+}
+
+pub fn extrapolate_backwards(history: &[i32]) -> i32 {
+    let reversed: Vec<i32> = history.iter().rev().copied().collect();
+    extrapolate(&reversed)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Parsed<'a> = Vec<Vec<i32>>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Vec<Vec<i32>> {
+        parse_input(input)
+    }
+
+    fn part1(histories: &Vec<Vec<i32>>) -> i32 {
+        histories.iter().map(|x| extrapolate(x)).sum()
+    }
+
+    fn part2(histories: &Vec<Vec<i32>>) -> i32 {
+        histories.iter().map(|x| extrapolate_backwards(x)).sum()
+    }
+}
+
+impl StreamingSolution for Day09 {
+    fn part1_streaming<R: BufRead>(reader: R) -> io::Result<i32> {
+        stream::lines(reader)
+            .map(|line| line.map(|l| extrapolate(&parse_number_list(&l))))
+            .sum()
+    }
+
+    fn part2_streaming<R: BufRead>(reader: R) -> io::Result<i32> {
+        stream::lines(reader)
+            .map(|line| line.map(|l| extrapolate_backwards(&parse_number_list(&l))))
+            .sum()
+    }
+}
+
+pub const EXAMPLE: &str = "\
+    0 3 6 9 12 15
+    1 3 6 10 15 21
+    10 13 16 21 30 45";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_example1() {
+        assert_eq!(Day09::solve1(EXAMPLE), 114);
+    }
+
+    #[test]
+    fn example1_step_by_step() {
+        assert_eq!(extrapolate(&[0, 3, 6, 9, 12, 15]), 18);
+        assert_eq!(extrapolate(&[1, 3, 6, 10, 15, 21]), 28);
+        assert_eq!(extrapolate(&[10, 13, 16, 21, 30, 45]), 68);
+    }
+
+    #[test]
+    fn example2() {
+        assert_eq!(Day09::solve2(EXAMPLE), 2)
+    }
+
+    #[test]
+    fn streaming() {
+        assert_eq!(Day09::part1_streaming(EXAMPLE.as_bytes()).unwrap(), 114);
+        assert_eq!(Day09::part2_streaming(EXAMPLE.as_bytes()).unwrap(), 2);
+    }
+}
@@ -0,0 +1,287 @@
+use aoc::stream::{self, StreamingSolution};
+use aoc::Solution;
+use cached::proc_macro::cached;
+use std::io::{self, BufRead};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+pub enum SpringCondition {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+impl SpringCondition {
+    pub fn parse(input: char) -> Self {
+        match input {
+            '.' => Self::Operational,
+            '#' => Self::Damaged,
+            '?' => Self::Unknown,
+            _ => panic!(),
+        }
+    }
+
+    pub fn char(&self) -> char {
+        match self {
+            Self::Operational => '.',
+            Self::Damaged => '#',
+            Self::Unknown => '?',
+        }
+    }
+}
+
+pub struct Spring {
+    pub conditions: Vec<SpringCondition>,
+    pub damaged_segments: Vec<usize>,
+}
+
+impl Spring {
+    pub fn parse(input: &str) -> Self {
+        let (conditions_str, segments_str) = input.split_once(' ').unwrap();
+        let conditions = conditions_str.chars().map(SpringCondition::parse).collect();
+        let damaged_segments = segments_str
+            .split(',')
+            .map(|c| c.parse().unwrap())
+            .collect();
+
+        Self {
+            conditions,
+            damaged_segments,
+        }
+    }
+
+    pub fn unfold(&self) -> Self {
+        let mut conditions = Vec::new();
+        let mut damaged_segments = Vec::new();
+        for i in 0..5 {
+            conditions.extend(self.conditions.iter());
+            if i < 4 {
+                conditions.push(SpringCondition::Unknown);
+            }
+            damaged_segments.extend(self.damaged_segments.iter());
+        }
+
+        Self {
+            conditions,
+            damaged_segments,
+        }
+    }
+}
+
+pub fn condition_segments(conditions: &[SpringCondition]) -> Vec<usize> {
+    let mut in_segment = false;
+    let mut segment_size = 0;
+    let mut segments = Vec::new();
+    for cond in conditions {
+        match (cond, in_segment) {
+            (SpringCondition::Damaged, true) => segment_size += 1,
+            (SpringCondition::Damaged, false) => {
+                in_segment = true;
+                segment_size += 1;
+            }
+            (SpringCondition::Operational, true) => {
+                segments.push(segment_size);
+                in_segment = false;
+                segment_size = 0;
+            }
+            (SpringCondition::Operational, false) => {}
+            _ => panic!("condition should not be unknown here"),
+        }
+    }
+    if in_segment {
+        segments.push(segment_size);
+    }
+    segments
+}
+
+#[derive(Debug)]
+pub struct ConditionStats {
+    pub num_operational: usize,
+    pub num_damaged: usize,
+    pub num_unknown: usize,
+    pub fixed_until: usize,
+}
+
+impl ConditionStats {
+    pub fn from(conditions: &[SpringCondition]) -> Self {
+        let mut num_operational = 0;
+        let mut num_damaged = 0;
+        let mut num_unknown = 0;
+        let mut fixed_until = 0;
+
+        for (i, cond) in conditions.iter().enumerate() {
+            match cond {
+                SpringCondition::Operational => {
+                    num_operational += 1;
+                    if num_unknown == 0 {
+                        fixed_until = i
+                    };
+                }
+                SpringCondition::Damaged => num_damaged += 1,
+                SpringCondition::Unknown => num_unknown += 1,
+            }
+        }
+
+        Self {
+            num_operational,
+            num_damaged,
+            num_unknown,
+            fixed_until,
+        }
+    }
+}
+
+#[cached(size = 1_000_000)]
+// memoization with "cached" requires taking ownership of parameters; the cache is bounded
+// so long streamed inputs do not grow it without limit
+pub fn n_arrangements(conditions: Vec<SpringCondition>, segments: Vec<usize>) -> usize {
+    let stats = ConditionStats::from(&conditions);
+    if stats.num_unknown == 0 {
+        if condition_segments(&conditions) == segments {
+            return 1;
+        } else {
+            return 0;
+        }
+    }
+
+    let target_damaged = segments.iter().sum();
+
+    if stats.num_damaged > target_damaged {
+        return 0;
+    }
+    if stats.num_damaged + stats.num_unknown < target_damaged {
+        return 0;
+    }
+
+    let (fixed_conditions, remaining_conditions) = conditions.split_at(stats.fixed_until);
+    let fixed_segments = condition_segments(fixed_conditions);
+
+    if fixed_segments.len() > segments.len() {
+        return 0;
+    }
+
+    let (done_segments, todo_segments) = segments.split_at(fixed_segments.len());
+    if done_segments != fixed_segments {
+        return 0;
+    }
+
+    let first_unknown = remaining_conditions
+        .iter()
+        .position(|&c| c == SpringCondition::Unknown)
+        .unwrap();
+    let mut s1 = Vec::from(remaining_conditions);
+    let mut s2 = Vec::from(remaining_conditions);
+    s1[first_unknown] = SpringCondition::Damaged;
+    s2[first_unknown] = SpringCondition::Operational;
+
+    n_arrangements(s1, todo_segments.to_vec()) + n_arrangements(s2, todo_segments.to_vec())
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed<'a> = Vec<Spring>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Vec<Spring> {
+        input.lines().map(Spring::parse).collect()
+    }
+
+    fn part1(springs: &Vec<Spring>) -> usize {
+        springs
+            .iter()
+            .map(|s| n_arrangements(s.conditions.clone(), s.damaged_segments.clone()))
+            .sum()
+    }
+
+    fn part2(springs: &Vec<Spring>) -> usize {
+        springs
+            .iter()
+            .map(Spring::unfold)
+            .map(|s| n_arrangements(s.conditions, s.damaged_segments))
+            .sum()
+    }
+}
+
+impl StreamingSolution for Day12 {
+    fn part1_streaming<R: BufRead>(reader: R) -> io::Result<usize> {
+        stream::lines(reader)
+            .map(|line| {
+                line.map(|l| {
+                    let s = Spring::parse(&l);
+                    n_arrangements(s.conditions, s.damaged_segments)
+                })
+            })
+            .sum()
+    }
+
+    fn part2_streaming<R: BufRead>(reader: R) -> io::Result<usize> {
+        stream::lines(reader)
+            .map(|line| {
+                line.map(|l| {
+                    let s = Spring::parse(&l).unfold();
+                    n_arrangements(s.conditions, s.damaged_segments)
+                })
+            })
+            .sum()
+    }
+}
+
+pub const EXAMPLE: &str = "\
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example1() {
+        assert_eq!(Day12::solve1(EXAMPLE), 21);
+    }
+
+    #[test]
+    fn test_conditions_match_segments() {
+        let s1 = Spring::parse("#.#.### 1,1,3");
+        assert_eq!(condition_segments(&s1.conditions), s1.damaged_segments);
+
+        let s2 = Spring::parse(".#...#....###. 1,1,3");
+        assert_eq!(condition_segments(&s2.conditions), s2.damaged_segments);
+
+        let s3 = Spring::parse("#....######..#####. 1,6,5,1");
+        assert_ne!(condition_segments(&s3.conditions), s3.damaged_segments);
+    }
+
+    #[test]
+    fn parse_folded() {
+        let s = Spring::parse("???.### 1,1,3").unfold();
+        assert_eq!(
+            s.conditions
+                .iter()
+                .map(|c| c.char().to_string())
+                .collect::<Vec<_>>()
+                .join(""),
+            "???.###????.###????.###????.###????.###"
+        );
+
+        assert_eq!(
+            s.damaged_segments,
+            vec![1, 1, 3, 1, 1, 3, 1, 1, 3, 1, 1, 3, 1, 1, 3]
+        );
+    }
+
+    #[test]
+    fn example2() {
+        assert_eq!(Day12::solve2(EXAMPLE), 525152);
+    }
+
+    #[test]
+    fn streaming() {
+        assert_eq!(Day12::part1_streaming(EXAMPLE.as_bytes()).unwrap(), 21);
+        assert_eq!(Day12::part2_streaming(EXAMPLE.as_bytes()).unwrap(), 525152);
+    }
+}
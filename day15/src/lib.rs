@@ -0,0 +1,167 @@
+use aoc::stream::{self, StreamingSolution};
+use aoc::Solution;
+use std::io::{self, BufRead};
+
+pub fn hash_algorithm(input: &str) -> u32 {
+    let mut current_value = 0;
+    for char in input.chars() {
+        current_value += char as u32;
+        current_value *= 17;
+        current_value %= 256;
+    }
+    current_value
+}
+
+#[derive(Debug, Clone)]
+pub enum Operation {
+    Remove { label: String },
+    Set { label: String, focal_length: u8 },
+}
+
+impl Operation {
+    pub fn parse(input: &str) -> Self {
+        if let Some(label) = input.strip_suffix('-') {
+            Self::Remove {
+                label: label.to_string(),
+            }
+        } else {
+            Self::Set {
+                label: input[..input.len() - 2].to_string(),
+                focal_length: input[input.len() - 1..].parse().unwrap(),
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Lens {
+    pub label: String,
+    pub focal_length: u8,
+}
+
+pub struct Boxes {
+    pub boxes: Vec<Vec<Lens>>,
+}
+
+impl Boxes {
+    pub fn new() -> Self {
+        Self {
+            boxes: vec![Vec::new(); 256],
+        }
+    }
+
+    pub fn apply(&mut self, op: Operation) {
+        match op {
+            Operation::Remove { label } => {
+                let op_box = &mut self.boxes[hash_algorithm(&label) as usize];
+                if let Some(position) = &op_box.iter().position(|lens| lens.label == label) {
+                    op_box.remove(*position);
+                }
+            }
+            Operation::Set {
+                label,
+                focal_length,
+            } => {
+                let op_box = &mut self.boxes[hash_algorithm(&label) as usize];
+                if let Some(position) = &op_box.iter().position(|lens| lens.label == label) {
+                    let lens = op_box.get_mut(*position).unwrap();
+                    lens.focal_length = focal_length
+                } else {
+                    op_box.push(Lens {
+                        label,
+                        focal_length,
+                    });
+                }
+            }
+        }
+    }
+
+    pub fn focusing_power(&self) -> usize {
+        self.boxes
+            .iter()
+            .enumerate()
+            .flat_map(|(box_num_zero_indexed, b)| {
+                b.iter().enumerate().map(move |(slot_num_zero_indexed, l)| {
+                    (box_num_zero_indexed + 1)
+                        * (slot_num_zero_indexed + 1)
+                        * (l.focal_length as usize)
+                })
+            })
+            .sum()
+    }
+}
+
+impl Default for Boxes {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Parsed<'a> = Vec<&'a str>;
+    type Output1 = u32;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Vec<&str> {
+        input.split(',').collect()
+    }
+
+    fn part1(steps: &Vec<&str>) -> u32 {
+        steps.iter().copied().map(hash_algorithm).sum()
+    }
+
+    fn part2(steps: &Vec<&str>) -> usize {
+        let mut boxes = Boxes::new();
+        for op in steps.iter().copied().map(Operation::parse) {
+            boxes.apply(op);
+        }
+        boxes.focusing_power()
+    }
+}
+
+impl StreamingSolution for Day15 {
+    fn part1_streaming<R: BufRead>(reader: R) -> io::Result<u32> {
+        stream::records(reader, b',')
+            .map(|step| step.map(|s| hash_algorithm(&s)))
+            .sum()
+    }
+
+    fn part2_streaming<R: BufRead>(reader: R) -> io::Result<usize> {
+        let mut boxes = Boxes::new();
+        for step in stream::records(reader, b',') {
+            boxes.apply(Operation::parse(&step?));
+        }
+        Ok(boxes.focusing_power())
+    }
+}
+
+pub const EXAMPLE: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_algorithm() {
+        assert_eq!(hash_algorithm("HASH"), 52);
+    }
+
+    #[test]
+    fn example1() {
+        assert_eq!(Day15::solve1(EXAMPLE), 1320);
+    }
+
+    #[test]
+    fn example2() {
+        assert_eq!(Day15::solve2(EXAMPLE), 145);
+    }
+
+    #[test]
+    fn streaming() {
+        let input = format!("{EXAMPLE}\n");
+        assert_eq!(Day15::part1_streaming(input.as_bytes()).unwrap(), 1320);
+        assert_eq!(Day15::part2_streaming(input.as_bytes()).unwrap(), 145);
+    }
+}
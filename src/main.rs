@@ -1 +1,144 @@
-fn main() {}
+use aoc::{config::Config, stream::run_streaming, OutputFormat};
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    process,
+};
+
+type Runner = fn(&str, &str, OutputFormat);
+type StreamingRunner = fn(&Path) -> io::Result<()>;
+
+const DAYS: &[(&str, Runner)] = &[
+    ("day01", aoc::run_named::<day01::Day01>),
+    ("day02", aoc::run_named::<day02::Day02>),
+    ("day03", aoc::run_named::<day03::Day03>),
+    ("day04", aoc::run_named::<day04::Day04>),
+    ("day05", aoc::run_named::<day05::Day05>),
+    ("day06", aoc::run_named::<day06::Day06>),
+    ("day07", aoc::run_named::<day07::Day07>),
+    ("day08", aoc::run_named::<day08::Day08>),
+    ("day09", aoc::run_named::<day09::Day09>),
+    ("day10", aoc::run_named::<day10::Day10>),
+    ("day11", aoc::run_named::<day11::Day11>),
+    ("day12", aoc::run_named::<day12::Day12>),
+    ("day13", aoc::run_named::<day13::Day13>),
+    ("day14", aoc::run_named::<day14::Day14>),
+    ("day15", aoc::run_named::<day15::Day15>),
+    ("day16", aoc::run_named::<day16::Day16>),
+    ("day17", aoc::run_named::<day17::Day17>),
+    ("day18", aoc::run_named::<day18::Day18>),
+    ("day19", aoc::run_named::<day19::Day19>),
+];
+
+const STREAMING_DAYS: &[(&str, StreamingRunner)] = &[
+    ("day01", run_streaming::<day01::Day01>),
+    ("day02", run_streaming::<day02::Day02>),
+    ("day04", run_streaming::<day04::Day04>),
+    ("day07", run_streaming::<day07::Day07>),
+    ("day09", run_streaming::<day09::Day09>),
+    ("day12", run_streaming::<day12::Day12>),
+    ("day15", run_streaming::<day15::Day15>),
+];
+
+#[derive(Default)]
+struct Args {
+    config_path: Option<PathBuf>,
+    inputs_dir: Option<PathBuf>,
+    output_format: Option<OutputFormat>,
+    session_token_path: Option<PathBuf>,
+    stream_path: Option<PathBuf>,
+    days: Vec<String>,
+}
+
+impl Args {
+    fn parse(args: impl Iterator<Item = String>) -> Self {
+        let mut result = Self::default();
+        let mut args = args.peekable();
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .unwrap_or_else(|| exit_with(format!("{arg} needs a value")))
+            };
+            match arg.as_str() {
+                "--config" => result.config_path = Some(value().into()),
+                "--inputs" => result.inputs_dir = Some(value().into()),
+                "--format" => {
+                    result.output_format = Some(
+                        value()
+                            .parse::<OutputFormat>()
+                            .unwrap_or_else(|e| exit_with(e)),
+                    )
+                }
+                "--session" => result.session_token_path = Some(value().into()),
+                "--stream" => result.stream_path = Some(value().into()),
+                flag if flag.starts_with("--") => exit_with(format!("unknown flag: {flag}")),
+                _ => result.days.push(arg),
+            }
+        }
+        result
+    }
+}
+
+fn exit_with(message: impl Display) -> ! {
+    eprintln!("error: {message}");
+    process::exit(1)
+}
+
+fn main() {
+    let args = Args::parse(env::args().skip(1));
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+    let mut config = match &args.config_path {
+        Some(path) => Config::load(path),
+        None => Config::load_or_default(&workspace.join("aoc.toml")),
+    }
+    .unwrap_or_else(|e| exit_with(e));
+
+    if args.inputs_dir.is_some() {
+        config.inputs_dir = args.inputs_dir;
+    }
+    if let Some(format) = args.output_format {
+        config.output_format = format;
+    }
+    if args.session_token_path.is_some() {
+        config.session_token_path = args.session_token_path;
+    }
+    if !args.days.is_empty() {
+        config.days = Some(args.days);
+    }
+
+    let known_days: Vec<&str> = DAYS.iter().map(|(name, _)| *name).collect();
+    config
+        .validate(&known_days)
+        .unwrap_or_else(|e| exit_with(e));
+
+    if let Some(path) = args.stream_path {
+        let [day] = config.days.as_deref().unwrap_or_default() else {
+            exit_with("--stream needs exactly one day");
+        };
+        if config.output_format != OutputFormat::Text {
+            exit_with("--stream only supports text output");
+        }
+        let (_, run) = STREAMING_DAYS
+            .iter()
+            .find(|(name, _)| name == day)
+            .unwrap_or_else(|| exit_with(format!("{day} does not support streaming")));
+
+        println!("{day}");
+        run(&path).unwrap_or_else(|e| exit_with(format!("could not read {}: {e}", path.display())));
+        return;
+    }
+
+    for (name, run) in DAYS {
+        if !config.is_enabled(name) {
+            continue;
+        }
+        let input_path = config.input_path(workspace, name);
+        let input = fs::read_to_string(&input_path)
+            .unwrap_or_else(|e| exit_with(format!("could not read {}: {e}", input_path.display())));
+
+        run(name, &input, config.output_format);
+    }
+}
@@ -0,0 +1,50 @@
+use aoc::Solution;
+
+pub struct DayXX;
+
+impl Solution for DayXX {
+    type Parsed<'a> = Vec<&'a str>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Vec<&str> {
+        input.lines().collect()
+    }
+
+    fn part1(parsed: &Vec<&str>) -> u32 {
+        todo!()
+    }
+
+    fn part2(parsed: &Vec<&str>) -> u32 {
+        todo!()
+    }
+}
+
+pub const EXAMPLE: &str = "\
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example1() {
+        assert_eq!(DayXX::solve1(EXAMPLE), 142);
+    }
+
+    #[test]
+    fn example2() {
+        let input = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+        assert_eq!(DayXX::solve2(input), 281);
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc::run::<dayXX::DayXX>(include_str!("input.txt"));
+}